@@ -0,0 +1,96 @@
+use std::cmp::Ordering;
+use crate::duration::Duration;
+use crate::types::*;
+
+/// Krumhansl-Kessler probe-tone ratings for a major key, starting from the tonic.
+const MAJOR_PROFILE: [f64; 12] = [6.35, 2.23, 3.48, 2.33, 4.38, 4.09, 2.52, 5.19, 2.39, 3.66, 2.29, 2.88];
+
+/// Krumhansl-Kessler probe-tone ratings for a minor key, starting from the tonic.
+const MINOR_PROFILE: [f64; 12] = [6.33, 2.68, 3.52, 5.38, 2.60, 3.53, 2.54, 4.75, 3.98, 2.69, 3.34, 3.17];
+
+/// Returns the pitch class of a step and alteration, with C as 0. Microtonal alterations are rounded to the nearest semitone.
+fn pitch_class(step: Step, alter: Semitones) -> usize {
+	(step.semitone() + alter.round() as i64).rem_euclid(12) as usize
+}
+
+/// Returns the Pearson correlation of a pitch-class histogram with a key profile rotated to the given tonic.
+fn correlation(histogram: &[f64; 12], profile: &[f64; 12], tonic: usize) -> f64 {
+	let histogram_mean = histogram.iter().sum::<f64>() / 12.0;
+	let profile_mean = profile.iter().sum::<f64>() / 12.0;
+	let mut covariance = 0.0;
+	let mut histogram_variance = 0.0;
+	let mut profile_variance = 0.0;
+	for (pitch_class, &weight) in histogram.iter().enumerate() {
+		let x = weight - histogram_mean;
+		let y = profile[(pitch_class + 12 - tonic) % 12] - profile_mean;
+		covariance += x * y;
+		histogram_variance += x * x;
+		profile_variance += y * y;
+	}
+	if histogram_variance == 0.0 {
+		return 0.0;
+	}
+	covariance / (histogram_variance * profile_variance).sqrt()
+}
+
+/// Estimates the key of a passage from its pitches and their durations, using the Krumhansl-Schmuckler algorithm: the duration-weighted pitch-class histogram is correlated with the major and minor key profiles for every tonic. Returns the 24 major and minor keys with their correlations, best match first, or an empty list if the passage has no sounding duration.
+pub fn estimate_key<'a, I>(notes: I) -> Vec<(Key, f32)>
+where
+	I: IntoIterator<Item = (&'a Pitch, Duration)>,
+{
+	let mut histogram = [0.0; 12];
+	for (pitch, duration) in notes {
+		histogram[pitch_class(pitch.step, pitch.alter.unwrap_or(0.0))] += duration.as_f64();
+	}
+	if histogram.iter().all(|&weight| weight == 0.0) {
+		return Vec::new();
+	}
+	let mut keys = Vec::with_capacity(24);
+	for &(ref mode, profile, first_fifths) in [(Mode::Major, &MAJOR_PROFILE, -6), (Mode::Minor, &MINOR_PROFILE, -5)].iter() {
+		for fifths in first_fifths..first_fifths + 12 {
+			let key = Key::from_fifths(fifths, Some(mode.clone()));
+			let (step, alter) = key.tonic(mode).unwrap();
+			let score = correlation(&histogram, profile, pitch_class(step, alter));
+			keys.push((key, score as f32));
+		}
+	}
+	keys.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+	keys
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn pitch(step: Step, alter: Semitones) -> Pitch {
+		Pitch { step, alter: Some(alter), octave: 4 }
+	}
+
+	#[test]
+	fn estimate_key_ranks_keys() {
+		let scale = [
+			(pitch(Step::C, 0.0), Duration::new(1, 2)),
+			(pitch(Step::D, 0.0), Duration::new(1, 8)),
+			(pitch(Step::E, 0.0), Duration::new(1, 4)),
+			(pitch(Step::F, 0.0), Duration::new(1, 8)),
+			(pitch(Step::G, 0.0), Duration::new(1, 4)),
+			(pitch(Step::A, 0.0), Duration::new(1, 8)),
+			(pitch(Step::B, 0.0), Duration::new(1, 8)),
+		];
+		let keys = estimate_key(scale.iter().map(|(pitch, duration)| (pitch, *duration)));
+		assert_eq!(keys.len(), 24);
+		assert_eq!(keys[0].0, Key::from_fifths(0, Some(Mode::Major)));
+		assert!(keys.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+		let minor = [
+			(pitch(Step::A, 0.0), Duration::new(1, 1)),
+			(pitch(Step::C, 0.0), Duration::new(1, 4)),
+			(pitch(Step::E, 0.0), Duration::new(1, 2)),
+			(pitch(Step::G, 1.0), Duration::new(1, 4)),
+		];
+		let keys = estimate_key(minor.iter().map(|(pitch, duration)| (pitch, *duration)));
+		assert_eq!(keys[0].0, Key::from_fifths(0, Some(Mode::Minor)));
+
+		assert!(estimate_key(std::iter::empty()).is_empty());
+	}
+}
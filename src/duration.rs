@@ -0,0 +1,237 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, Sub};
+use crate::types::*;
+
+/// A musical duration expressed exactly as a fraction of a whole note. Durations convert between MusicXML divisions, note types with dots and tuplet ratios, and plain fractions without the rounding errors of floating-point divisions values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Duration {
+	numerator: u64,
+	denominator: u64,
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+	while b != 0 {
+		let r = a % b;
+		a = b;
+		b = r;
+	}
+	a
+}
+
+impl Duration {
+	/// The empty duration.
+	pub const ZERO: Duration = Duration { numerator: 0, denominator: 1 };
+
+	/// Creates a duration of `numerator / denominator` whole notes, reduced to lowest terms. Panics if the denominator is zero; use `checked_new` for values read from a document.
+	pub fn new(numerator: u64, denominator: u64) -> Duration {
+		Duration::checked_new(numerator, denominator).expect("duration denominator must not be zero")
+	}
+
+	/// Creates a duration of `numerator / denominator` whole notes, reduced to lowest terms, or None if the denominator is zero.
+	pub fn checked_new(numerator: u64, denominator: u64) -> Option<Duration> {
+		Duration::from_u128(u128::from(numerator), u128::from(denominator))
+	}
+
+	/// Reduces a fraction computed with wide intermediates, returning None if the denominator is zero or the reduced fraction does not fit.
+	fn from_u128(numerator: u128, denominator: u128) -> Option<Duration> {
+		if denominator == 0 {
+			return None;
+		}
+		let divisor = gcd(numerator, denominator);
+		Some(Duration {
+			numerator: u64::try_from(numerator / divisor).ok()?,
+			denominator: u64::try_from(denominator / divisor).ok()?,
+		})
+	}
+
+	/// Returns the numerator of the fraction of a whole note, in lowest terms.
+	pub fn numerator(&self) -> u64 {
+		self.numerator
+	}
+
+	/// Returns the denominator of the fraction of a whole note, in lowest terms.
+	pub fn denominator(&self) -> u64 {
+		self.denominator
+	}
+
+	/// Creates the duration of a note of the given type with the given number of dots. Each dot adds half of the value added by the previous one. Returns None if there are too many dots for the result to fit.
+	pub fn from_note_type(note: NoteTypeValue, dots: u32) -> Option<Duration> {
+		let (numerator, denominator) = match note {
+			NoteTypeValue::Maxima => (8, 1),
+			NoteTypeValue::Long => (4, 1),
+			NoteTypeValue::Breve => (2, 1),
+			_ => (1, u128::from(note.beat_type()?)),
+		};
+		let dot_factor = u128::from(1u64.checked_shl(dots)?);
+		Duration::from_u128(numerator * (2 * dot_factor - 1), denominator * dot_factor)
+	}
+
+	/// Scales the duration by a tuplet ratio, where `actual_notes` notes take the time of `normal_notes`, as in the time-modification element. Returns None if `actual_notes` is zero or the result does not fit.
+	pub fn with_tuplet(self, actual_notes: u64, normal_notes: u64) -> Option<Duration> {
+		Duration::from_u128(
+			u128::from(self.numerator) * u128::from(normal_notes),
+			u128::from(self.denominator) * u128::from(actual_notes),
+		)
+	}
+
+	/// Creates a duration from a value in divisions, given the number of divisions per quarter note. Returns None if the number of divisions is zero or the result does not fit.
+	pub fn from_divisions(duration: u64, divisions: u64) -> Option<Duration> {
+		Duration::from_u128(u128::from(duration), u128::from(divisions) * 4)
+	}
+
+	/// Converts the duration to divisions, given the number of divisions per quarter note. Returns None if the duration is not a whole number of divisions or the result does not fit.
+	pub fn to_divisions(&self, divisions: u64) -> Option<u64> {
+		let scaled = u128::from(self.numerator) * u128::from(divisions) * 4;
+		let denominator = u128::from(self.denominator);
+		if scaled.is_multiple_of(denominator) {
+			u64::try_from(scaled / denominator).ok()
+		} else {
+			None
+		}
+	}
+
+	/// Adds two durations, returning None if the result does not fit.
+	pub fn checked_add(self, other: Duration) -> Option<Duration> {
+		let left = u128::from(self.numerator) * u128::from(other.denominator);
+		let right = u128::from(other.numerator) * u128::from(self.denominator);
+		Duration::from_u128(left.checked_add(right)?, u128::from(self.denominator) * u128::from(other.denominator))
+	}
+
+	/// Subtracts a duration, returning None if `other` is longer than `self`.
+	pub fn checked_sub(self, other: Duration) -> Option<Duration> {
+		let left = u128::from(self.numerator) * u128::from(other.denominator);
+		let right = u128::from(other.numerator) * u128::from(self.denominator);
+		Duration::from_u128(left.checked_sub(right)?, u128::from(self.denominator) * u128::from(other.denominator))
+	}
+
+	/// Subtracts a duration, returning zero if `other` is longer than `self`.
+	pub fn saturating_sub(self, other: Duration) -> Duration {
+		self.checked_sub(other).unwrap_or(Duration::ZERO)
+	}
+
+	/// Returns the note type and number of dots (up to four) that represent this duration without a tuplet, if any.
+	pub fn to_note_type(&self) -> Option<(NoteTypeValue, u32)> {
+		const NOTE_TYPES: [NoteTypeValue; 14] = [
+			NoteTypeValue::Maxima,
+			NoteTypeValue::Long,
+			NoteTypeValue::Breve,
+			NoteTypeValue::Whole,
+			NoteTypeValue::Half,
+			NoteTypeValue::Quarter,
+			NoteTypeValue::Eighth,
+			NoteTypeValue::_16th,
+			NoteTypeValue::_32nd,
+			NoteTypeValue::_64th,
+			NoteTypeValue::_128th,
+			NoteTypeValue::_256th,
+			NoteTypeValue::_512th,
+			NoteTypeValue::_1024th,
+		];
+		for dots in 0..=4 {
+			for &note in NOTE_TYPES.iter() {
+				if Duration::from_note_type(note, dots) == Some(*self) {
+					return Some((note, dots));
+				}
+			}
+		}
+		None
+	}
+
+	/// Returns the duration as a floating-point fraction of a whole note.
+	pub fn as_f64(&self) -> f64 {
+		self.numerator as f64 / self.denominator as f64
+	}
+}
+
+impl Default for Duration {
+	fn default() -> Self {
+		Duration::ZERO
+	}
+}
+
+/// Adds two durations. Panics if the result does not fit, like integer overflow; use `checked_add` to handle it.
+impl Add for Duration {
+	type Output = Duration;
+
+	fn add(self, other: Duration) -> Duration {
+		self.checked_add(other).expect("duration overflow")
+	}
+}
+
+/// Subtracts two durations. Panics if `other` is longer than `self`, like integer underflow; use `checked_sub` or `saturating_sub` to handle it.
+impl Sub for Duration {
+	type Output = Duration;
+
+	fn sub(self, other: Duration) -> Duration {
+		self.checked_sub(other).expect("duration underflow")
+	}
+}
+
+impl PartialOrd for Duration {
+	fn partial_cmp(&self, other: &Duration) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for Duration {
+	fn cmp(&self, other: &Duration) -> Ordering {
+		let left = u128::from(self.numerator) * u128::from(other.denominator);
+		let right = u128::from(other.numerator) * u128::from(self.denominator);
+		left.cmp(&right)
+	}
+}
+
+impl fmt::Display for Duration {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}/{}", self.numerator, self.denominator)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn arithmetic() {
+		let quarter = Duration::new(1, 4);
+		let eighth = Duration::new(2, 16);
+		assert_eq!(eighth, Duration::new(1, 8));
+		assert_eq!(quarter + eighth, Duration::new(3, 8));
+		assert_eq!(quarter - eighth, eighth);
+		assert_eq!(eighth.saturating_sub(quarter), Duration::ZERO);
+		assert_eq!(eighth.checked_sub(quarter), None);
+		assert!(eighth < quarter);
+		let large = Duration::new(u64::MAX - 1, u64::MAX);
+		assert_eq!(large - large, Duration::ZERO);
+		assert!(large < Duration::new(1, 1));
+		assert_eq!(Duration::checked_new(1, 0), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "duration underflow")]
+	fn sub_underflow() {
+		let _ = Duration::new(1, 8) - Duration::new(1, 4);
+	}
+
+	#[test]
+	fn divisions() {
+		assert_eq!(Duration::from_divisions(3, 2), Some(Duration::new(3, 8)));
+		assert_eq!(Duration::from_divisions(3, 0), None);
+		assert_eq!(Duration::new(3, 8).to_divisions(2), Some(3));
+		assert_eq!(Duration::new(1, 3).to_divisions(2), None);
+		assert_eq!(Duration::new(1, 8).with_tuplet(3, 2), Some(Duration::new(1, 12)));
+		assert_eq!(Duration::new(1, 8).with_tuplet(0, 2), None);
+	}
+
+	#[test]
+	fn note_types() {
+		assert_eq!(Duration::from_note_type(NoteTypeValue::Quarter, 1), Some(Duration::new(3, 8)));
+		assert_eq!(Duration::from_note_type(NoteTypeValue::Maxima, 2), Some(Duration::new(14, 1)));
+		assert_eq!(Duration::from_note_type(NoteTypeValue::_1024th, 63), None);
+		assert_eq!(Duration::from_note_type(NoteTypeValue::Quarter, 64), None);
+		assert_eq!(Duration::new(7, 16).to_note_type(), Some((NoteTypeValue::Quarter, 2)));
+		assert_eq!(Duration::new(1, 5).to_note_type(), None);
+	}
+}
@@ -0,0 +1,96 @@
+use crate::types::*;
+
+/// Selects whether human-readable output uses Unicode music symbols or plain ASCII text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SymbolStyle {
+	Unicode,
+	Ascii,
+}
+
+/// Returns a short human-readable symbol for a note type. Note types without a Unicode glyph fall back to their ASCII name.
+pub fn note_symbol(note: &NoteTypeValue, style: SymbolStyle) -> &'static str {
+	if style == SymbolStyle::Unicode {
+		match note {
+			NoteTypeValue::_128th => return "\u{1D164}",
+			NoteTypeValue::_64th => return "\u{1D163}",
+			NoteTypeValue::_32nd => return "\u{1D162}",
+			NoteTypeValue::_16th => return "\u{1D161}",
+			NoteTypeValue::Eighth => return "\u{266A}",
+			NoteTypeValue::Quarter => return "\u{2669}",
+			NoteTypeValue::Half => return "\u{1D15E}",
+			NoteTypeValue::Whole => return "\u{1D15D}",
+			NoteTypeValue::Breve => return "\u{1D15C}",
+			NoteTypeValue::Long => return "\u{1D1B7}",
+			NoteTypeValue::Maxima => return "\u{1D1B6}",
+			_ => {}
+		}
+	}
+	match note {
+		NoteTypeValue::_1024th => "1024th",
+		NoteTypeValue::_512th => "512th",
+		NoteTypeValue::_256th => "256th",
+		NoteTypeValue::_128th => "128th",
+		NoteTypeValue::_64th => "64th",
+		NoteTypeValue::_32nd => "32nd",
+		NoteTypeValue::_16th => "16th",
+		NoteTypeValue::Eighth => "eighth",
+		NoteTypeValue::Quarter => "quarter",
+		NoteTypeValue::Half => "half",
+		NoteTypeValue::Whole => "whole",
+		NoteTypeValue::Breve => "breve",
+		NoteTypeValue::Long => "long",
+		NoteTypeValue::Maxima => "maxima",
+	}
+}
+
+/// Formats a metronome mark from its beat-unit, the number of beat-unit-dot elements, and the per-minute text, e.g. "♩. = 92" or "quarter. = 92".
+pub fn format_metronome(beat_unit: &NoteTypeValue, dots: usize, per_minute: &str, style: SymbolStyle) -> String {
+	format!("{}{} = {}", note_symbol(beat_unit, style), ".".repeat(dots), per_minute)
+}
+
+/// Formats a time signature together with its number of beats, e.g. "3/4 (3 beats)" or "6/8 (2 beats)". Composite signatures are joined with "+", and compound meters are counted in dotted beats. A senza-misura time is formatted as "senza misura".
+pub fn format_time_signature(time: &Time) -> String {
+	let signatures = time.signatures();
+	if signatures.is_empty() {
+		return "senza misura".to_string();
+	}
+	let text = signatures
+		.iter()
+		.map(|signature| format!("{}/{}", signature.beats, signature.beat_type))
+		.collect::<Vec<_>>()
+		.join("+");
+	let beats: u64 = signatures.iter().filter_map(TimeSignature::beat_count).map(u64::from).sum();
+	let count = if time.is_compound() { beats / 3 } else { beats };
+	let unit = if count == 1 { "beat" } else { "beats" };
+	format!("{} ({} {})", text, count, unit)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn metronome() {
+		assert_eq!(format_metronome(&NoteTypeValue::Quarter, 1, "92", SymbolStyle::Unicode), "\u{2669}. = 92");
+		assert_eq!(format_metronome(&NoteTypeValue::Half, 0, "c. 60", SymbolStyle::Ascii), "half = c. 60");
+		assert_eq!(note_symbol(&NoteTypeValue::_1024th, SymbolStyle::Unicode), "1024th");
+	}
+
+	#[test]
+	fn time_signature() {
+		let time = |xml: &str| -> Time { quick_xml::de::from_str(xml).unwrap() };
+		assert_eq!(format_time_signature(&time("<time><beats>3</beats><beat-type>4</beat-type></time>")), "3/4 (3 beats)");
+		assert_eq!(format_time_signature(&time("<time><beats>6</beats><beat-type>8</beat-type></time>")), "6/8 (2 beats)");
+		assert_eq!(format_time_signature(&time("<time><beats>3</beats><beat-type>8</beat-type></time>")), "3/8 (3 beats)");
+		assert_eq!(format_time_signature(&time("<time><beats>1</beats><beat-type>2</beat-type></time>")), "1/2 (1 beat)");
+		assert_eq!(
+			format_time_signature(&time("<time><beats>2</beats><beat-type>4</beat-type><beats>3</beats><beat-type>8</beat-type></time>")),
+			"2/4+3/8 (5 beats)"
+		);
+		assert_eq!(format_time_signature(&time("<time><senza-misura/></time>")), "senza misura");
+		assert_eq!(
+			format_time_signature(&time("<time><beats>4000000000</beats><beat-type>4</beat-type></time>")),
+			"4000000000/4 (4000000000 beats)"
+		);
+	}
+}
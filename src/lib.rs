@@ -1 +1,8 @@
-pub mod types;
\ No newline at end of file
+pub mod types;
+pub mod format;
+pub mod duration;
+pub mod analysis;
+pub mod playback;
+pub mod units;
+pub mod percussion;
+pub mod reformat;
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use crate::types::*;
+
+/// General MIDI percussion key numbers for common instrument-sound values from the MusicXML standard sounds list.
+const GENERAL_MIDI_SOUNDS: &[(&str, u8)] = &[
+	("drum.bass-drum", 36),
+	("drum.side-stick", 37),
+	("drum.snare-drum", 38),
+	("effect.hand-clap", 39),
+	("drum.tom-tom", 45),
+	("drum.tom-tom.low", 45),
+	("drum.tom-tom.high", 50),
+	("drum.tom-tom.floor", 41),
+	("metal.hi-hat", 42),
+	("metal.hi-hat.closed", 42),
+	("metal.hi-hat.pedal", 44),
+	("metal.hi-hat.open", 46),
+	("metal.cymbal.crash", 49),
+	("metal.cymbal.ride", 51),
+	("metal.cymbal.chinese", 52),
+	("metal.bells.ride-bell", 53),
+	("metal.tambourine", 54),
+	("metal.cymbal.splash", 55),
+	("metal.cowbell", 56),
+	("drum.bongo", 60),
+	("drum.conga", 63),
+	("drum.timbale", 65),
+	("metal.agogo", 67),
+	("rattle.cabasa", 69),
+	("rattle.maraca", 70),
+	("wood.claves", 75),
+	("wood.wood-block", 76),
+	("metal.triangle", 81),
+	("rattle.shaker", 82),
+];
+
+/// Conventional drum staff positions for General MIDI percussion keys, as the step and octave displayed with a percussion clef.
+const GENERAL_MIDI_DISPLAY: &[(u8, Step, Octave)] = &[
+	(35, Step::E, 4),
+	(36, Step::F, 4),
+	(37, Step::C, 5),
+	(38, Step::C, 5),
+	(40, Step::C, 5),
+	(41, Step::A, 4),
+	(42, Step::G, 5),
+	(43, Step::G, 4),
+	(44, Step::D, 4),
+	(45, Step::D, 5),
+	(46, Step::G, 5),
+	(47, Step::D, 5),
+	(48, Step::E, 5),
+	(49, Step::A, 5),
+	(50, Step::E, 5),
+	(51, Step::F, 5),
+	(52, Step::A, 5),
+	(53, Step::F, 5),
+	(55, Step::A, 5),
+	(57, Step::A, 5),
+	(59, Step::F, 5),
+];
+
+/// Maps the instruments of a percussion part to MIDI key numbers and back to display positions. A note's instrument element refers to a score-instrument; its key is the midi-unpitched value of the matching midi-instrument if there is one, and otherwise looked up from the score-instrument's instrument-sound. Key numbers are the 0 to 127 values sent in MIDI messages.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PercussionMap {
+	sounds: HashMap<String, u8>,
+	display: HashMap<u8, (Step, Octave)>,
+}
+
+impl PercussionMap {
+	/// Creates an empty map, which only uses midi-unpitched assignments. This is the same as the default map; use general_midi for the General MIDI table.
+	pub fn new() -> PercussionMap {
+		PercussionMap::default()
+	}
+
+	/// Creates a map with the General MIDI keys for common drum kit and hand percussion sounds, and conventional drum staff positions for the drum kit.
+	pub fn general_midi() -> PercussionMap {
+		let mut map = PercussionMap::new();
+		for &(sound, key) in GENERAL_MIDI_SOUNDS {
+			map.set_sound(sound, key);
+		}
+		for &(key, step, octave) in GENERAL_MIDI_DISPLAY {
+			map.set_display(key, step, octave);
+		}
+		map
+	}
+
+	/// Sets the key played for an instrument-sound value.
+	pub fn set_sound(&mut self, sound: &str, key: u8) {
+		self.sounds.insert(sound.to_string(), key);
+	}
+
+	/// Sets the display step and octave used for a key.
+	pub fn set_display(&mut self, key: u8, step: Step, octave: Octave) {
+		self.display.insert(key, (step, octave));
+	}
+
+	/// Returns the key played for an instrument-sound value, if known.
+	pub fn sound_key(&self, sound: &str) -> Option<u8> {
+		self.sounds.get(sound).copied()
+	}
+
+	/// Returns the key played by the given score-instrument of a part, as referenced by a note's instrument element.
+	pub fn key(&self, part: &ScorePart, instrument: &str) -> Option<u8> {
+		if let Some(unpitched) = part.midi_instrument(instrument).and_then(|midi_instrument| midi_instrument.midi_unpitched) {
+			return Some(unpitched.to_midi());
+		}
+		let sound = part.score_instrument(instrument)?.instrument_sound.as_ref()?;
+		self.sound_key(sound)
+	}
+
+	/// Returns the score-instrument of a part that plays the given key, for assigning imported MIDI notes to instruments.
+	pub fn instrument<'a>(&self, part: &'a ScorePart, key: u8) -> Option<&'a ScoreInstrument> {
+		part.score_instrument
+			.iter()
+			.find(|score_instrument| self.key(part, &score_instrument.id) == Some(key))
+	}
+
+	/// Returns the display step and octave for a key, for placing imported MIDI notes on a percussion staff.
+	pub fn display(&self, key: u8) -> Option<(Step, Octave)> {
+		self.display.get(&key).copied()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn drum_part() -> ScorePart {
+		quick_xml::de::from_str(concat!(
+			r#"<score-part id="P1"><part-name>Drums</part-name>"#,
+			r#"<score-instrument id="P1-X1"><instrument-name>Snare</instrument-name><instrument-sound>drum.snare-drum</instrument-sound></score-instrument>"#,
+			r#"<score-instrument id="P1-X2"><instrument-name>Kick</instrument-name><instrument-sound>drum.bass-drum</instrument-sound></score-instrument>"#,
+			r#"<score-instrument id="P1-X3"><instrument-name>Bucket</instrument-name><instrument-sound>metal.bucket</instrument-sound></score-instrument>"#,
+			r#"<midi-instrument id="P1-X2"><midi-channel>10</midi-channel><midi-unpitched>37</midi-unpitched></midi-instrument>"#,
+			"</score-part>",
+		))
+		.unwrap()
+	}
+
+	#[test]
+	fn key() {
+		let part = drum_part();
+		let map = PercussionMap::general_midi();
+		assert_eq!(map.key(&part, "P1-X1"), Some(38));
+		// midi-unpitched takes precedence over the instrument-sound table.
+		assert_eq!(map.key(&part, "P1-X2"), Some(36));
+		assert_eq!(map.key(&part, "P1-X3"), None);
+		assert_eq!(map.key(&part, "P1-X4"), None);
+
+		let mut map = PercussionMap::new();
+		assert_eq!(map, PercussionMap::default());
+		assert_eq!(map.key(&part, "P1-X1"), None);
+		assert_eq!(map.key(&part, "P1-X2"), Some(36));
+		map.set_sound("metal.bucket", 80);
+		assert_eq!(map.key(&part, "P1-X3"), Some(80));
+	}
+
+	#[test]
+	fn instrument_and_display() {
+		let part = drum_part();
+		let map = PercussionMap::general_midi();
+		assert_eq!(map.instrument(&part, 38).map(|instrument| instrument.instrument_name.as_str()), Some("Snare"));
+		assert_eq!(map.instrument(&part, 36).map(|instrument| instrument.id.as_str()), Some("P1-X2"));
+		assert!(map.instrument(&part, 49).is_none());
+		assert_eq!(map.display(38), Some((Step::C, 5)));
+		assert_eq!(map.display(36), Some((Step::F, 4)));
+		assert_eq!(map.display(60), None);
+		assert_eq!(PercussionMap::default().display(38), None);
+	}
+}
@@ -0,0 +1,81 @@
+use crate::duration::Duration;
+use crate::types::*;
+
+/// Options controlling how notations that affect timing are rendered when a score is played back. Fermatas lengthen the note they are attached to, breath marks take time from the end of the note, and caesuras add a pause after it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaybackOptions {
+	/// The factor by which a note with a fermata is lengthened.
+	pub fermata_factor: f64,
+	/// The time taken from the end of a note followed by a breath mark. A note is never shortened by more than half its length.
+	pub breath_mark_pause: Duration,
+	/// The silence added after a note followed by a caesura.
+	pub caesura_pause: Duration,
+}
+
+impl Default for PlaybackOptions {
+	fn default() -> Self {
+		PlaybackOptions {
+			fermata_factor: 2.0,
+			breath_mark_pause: Duration::new(1, 16),
+			caesura_pause: Duration::new(1, 4),
+		}
+	}
+}
+
+/// The timing of a single note when played back, in whole notes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoteTiming {
+	/// How long the note sounds.
+	pub sounding: f64,
+	/// The silence following the note before the next one starts.
+	pub pause: f64,
+}
+
+impl NoteTiming {
+	/// Returns the total time taken by the note and the pause after it.
+	pub fn total(&self) -> f64 {
+		self.sounding + self.pause
+	}
+}
+
+impl PlaybackOptions {
+	/// Returns the playback timing of a note of the given notated duration, with the fermata, breath mark and caesura attached to it, if any. The breath mark pause is taken from the note after applying the fermata.
+	pub fn note_timing(&self, duration: Duration, fermata: Option<&Fermata>, breath_mark: Option<&BreathMark>, caesura: Option<&Caesura>) -> NoteTiming {
+		let mut sounding = duration.as_f64();
+		if fermata.is_some() {
+			sounding *= self.fermata_factor;
+		}
+		let mut pause = 0.0;
+		if breath_mark.is_some() {
+			let breath = self.breath_mark_pause.as_f64().min(sounding / 2.0);
+			sounding -= breath;
+			pause += breath;
+		}
+		if caesura.is_some() {
+			pause += self.caesura_pause.as_f64();
+		}
+		NoteTiming { sounding, pause }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn note_timing() {
+		let options = PlaybackOptions::default();
+		let fermata: Fermata = quick_xml::de::from_str("<fermata/>").unwrap();
+		let breath_mark: BreathMark = quick_xml::de::from_str("<breath-mark/>").unwrap();
+		let caesura: Caesura = quick_xml::de::from_str("<caesura/>").unwrap();
+		let quarter = Duration::new(1, 4);
+		assert_eq!(options.note_timing(quarter, None, None, None), NoteTiming { sounding: 0.25, pause: 0.0 });
+		assert_eq!(options.note_timing(quarter, Some(&fermata), None, None).sounding, 0.5);
+		let timing = options.note_timing(quarter, Some(&fermata), Some(&breath_mark), Some(&caesura));
+		assert_eq!(timing, NoteTiming { sounding: 0.4375, pause: 0.3125 });
+		assert_eq!(timing.total(), 0.75);
+		// A breath mark never takes more than half of the note.
+		let timing = options.note_timing(Duration::new(1, 16), None, Some(&breath_mark), None);
+		assert_eq!(timing, NoteTiming { sounding: 0.03125, pause: 0.03125 });
+	}
+}
@@ -0,0 +1,217 @@
+use std::borrow::Cow;
+use quick_xml::events::attributes::Attribute;
+use quick_xml::events::{BytesStart, BytesText, Event};
+use quick_xml::{Error, Reader, Writer};
+
+/// Elements that only carry page, system, staff, or measure layout.
+const LAYOUT_ELEMENTS: &[&[u8]] = &[b"page-layout", b"system-layout", b"staff-layout", b"measure-layout"];
+
+/// Attributes that only carry positions, in the position attribute group.
+const LAYOUT_ATTRIBUTES: &[&[u8]] = &[b"default-x", b"default-y", b"relative-x", b"relative-y"];
+
+/// Decimal attributes whose numbers are normalized: positions and measure widths in tenths, attack and release in divisions, and the tempo and dynamics of sound and note elements.
+const NUMBER_ATTRIBUTES: &[&[u8]] = &[
+	b"default-x",
+	b"default-y",
+	b"relative-x",
+	b"relative-y",
+	b"width",
+	b"attack",
+	b"release",
+	b"tempo",
+	b"dynamics",
+];
+
+/// Options controlling how a MusicXML document is reformatted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReformatOptions {
+	/// The number of spaces each nesting level is indented by.
+	pub indent: usize,
+	/// Whether attributes are sorted by name.
+	pub sort_attributes: bool,
+	/// Whether the values of position, width, attack, release, tempo, and dynamics attributes are written in a canonical decimal form, without a plus sign, leading zeros, or trailing fractional zeros. Other attributes, such as ids and numbers, are left as written.
+	pub normalize_numbers: bool,
+	/// Whether layout elements, position attributes, and measure widths are removed.
+	pub strip_layout: bool,
+}
+
+impl Default for ReformatOptions {
+	fn default() -> Self {
+		ReformatOptions {
+			indent: 2,
+			sort_attributes: true,
+			normalize_numbers: true,
+			strip_layout: false,
+		}
+	}
+}
+
+/// Returns the canonical form of a decimal number, or None if the text is not a plain decimal number.
+fn normalize_number(text: &str) -> Option<String> {
+	let (negative, digits) = match text.as_bytes().first()? {
+		b'-' => (true, &text[1..]),
+		b'+' => (false, &text[1..]),
+		_ => (false, text),
+	};
+	let (integer, fraction) = match digits.find('.') {
+		Some(point) => (&digits[..point], &digits[point + 1..]),
+		None => (digits, ""),
+	};
+	if integer.is_empty() && fraction.is_empty() {
+		return None;
+	}
+	if !integer.bytes().chain(fraction.bytes()).all(|byte| byte.is_ascii_digit()) {
+		return None;
+	}
+	let integer = integer.trim_start_matches('0');
+	let fraction = fraction.trim_end_matches('0');
+	let mut number = String::new();
+	if negative && !(integer.is_empty() && fraction.is_empty()) {
+		number.push('-');
+	}
+	number.push_str(if integer.is_empty() { "0" } else { integer });
+	if !fraction.is_empty() {
+		number.push('.');
+		number.push_str(fraction);
+	}
+	Some(number)
+}
+
+/// Returns a copy of a start tag with its attributes filtered, normalized, and sorted according to the options.
+fn reformat_start(start: &BytesStart, options: &ReformatOptions) -> Result<BytesStart<'static>, Error> {
+	let name = start.name();
+	let mut attributes = Vec::new();
+	for attribute in start.attributes() {
+		let attribute = attribute?;
+		if options.strip_layout
+			&& (LAYOUT_ATTRIBUTES.contains(&attribute.key) || (name == b"measure" && attribute.key == b"width"))
+		{
+			continue;
+		}
+		let mut value = attribute.value.into_owned();
+		if options.normalize_numbers && NUMBER_ATTRIBUTES.contains(&attribute.key) {
+			if let Some(number) = std::str::from_utf8(&value).ok().and_then(normalize_number) {
+				value = number.into_bytes();
+			}
+		}
+		attributes.push((attribute.key.to_vec(), value));
+	}
+	if options.sort_attributes {
+		attributes.sort_by(|a, b| a.0.cmp(&b.0));
+	}
+	let mut reformatted = BytesStart::owned(name.to_vec(), name.len());
+	for (key, value) in &attributes {
+		reformatted.push_attribute(Attribute {
+			key,
+			value: Cow::Borrowed(value),
+		});
+	}
+	Ok(reformatted)
+}
+
+/// Returns whether a text event only contains whitespace. Such text only indents the markup, unless it is the whole content of an element.
+fn is_whitespace(text: &BytesText) -> bool {
+	text.iter().all(u8::is_ascii_whitespace)
+}
+
+/// Reformats a MusicXML document: reindents it, drops whitespace-only text between elements, and optionally sorts attributes, normalizes numbers, and strips layout data. Text content, character data, comments, processing instructions, and the document type declaration are kept as written, so whitespace in text such as words and credit-words is preserved. Elements without content are written as empty elements.
+pub fn reformat(xml: &str, options: &ReformatOptions) -> Result<String, Error> {
+	let mut reader = Reader::from_str(xml);
+	let mut writer = Writer::new_with_indent(Vec::new(), b' ', options.indent);
+	let mut buffer = Vec::new();
+	let mut skipped_depth = 0;
+	// A start tag is held back until it is known whether the element has content, together with any whitespace-only text that may turn out to be that content.
+	let mut open: Option<BytesStart<'static>> = None;
+	let mut space: Option<BytesText<'static>> = None;
+	loop {
+		let event = reader.read_event(&mut buffer)?;
+		let is_layout = |start: &BytesStart| options.strip_layout && LAYOUT_ELEMENTS.contains(&start.name());
+		match event {
+			Event::Eof => break,
+			Event::Start(_) if skipped_depth > 0 => skipped_depth += 1,
+			Event::End(_) if skipped_depth > 0 => skipped_depth -= 1,
+			_ if skipped_depth > 0 => {},
+			Event::Text(ref text) if is_whitespace(text) => {
+				if open.is_some() && !text.is_empty() {
+					space = Some(text.clone().into_owned());
+				}
+			}
+			Event::End(end) => match (open.take(), space.take()) {
+				(Some(start), Some(text)) => {
+					writer.write_event(Event::Start(start))?;
+					writer.write_event(Event::Text(text))?;
+					writer.write_event(Event::End(end))?;
+				}
+				(Some(start), None) => {
+					writer.write_event(Event::Empty(start))?;
+				}
+				(None, _) => {
+					writer.write_event(Event::End(end))?;
+				}
+			},
+			event => {
+				space = None;
+				if let Some(start) = open.take() {
+					writer.write_event(Event::Start(start))?;
+				}
+				match event {
+					Event::Start(ref start) if is_layout(start) => skipped_depth = 1,
+					Event::Empty(ref start) if is_layout(start) => {},
+					Event::Start(ref start) => open = Some(reformat_start(start, options)?),
+					Event::Empty(ref start) => {
+						writer.write_event(Event::Empty(reformat_start(start, options)?))?;
+					}
+					event => {
+						writer.write_event(event)?;
+					}
+				}
+			}
+		}
+		buffer.clear();
+	}
+	let mut output = String::from_utf8(writer.into_inner()).map_err(|e| Error::Utf8(e.utf8_error()))?;
+	output.push('\n');
+	Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const SCORE: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>
+<score-partwise version="4.0">
+  <credit page="1"><credit-words xml:space="preserve" default-x="+0600.50">  Sonata  No. 1 </credit-words></credit>
+  <part-list><score-part id="P007"><part-name>Piano</part-name></score-part></part-list>
+  <part id="P007">
+    <measure number="01" width="200.0">
+      <direction><direction-type><words> dolce </words></direction-type><sound tempo="120.00"/></direction>
+      <note><rest/><duration>4</duration><lyric number="1"><text> </text></lyric></note>
+    </measure>
+  </part>
+</score-partwise>
+"#;
+
+	#[test]
+	fn reformat_keeps_content() {
+		let output = reformat(SCORE, &ReformatOptions::default()).unwrap();
+		assert!(output.contains(r#"<credit-words default-x="600.5" xml:space="preserve">  Sonata  No. 1 </credit-words>"#));
+		assert!(output.contains(r#"<score-part id="P007">"#));
+		assert!(output.contains(r#"<measure number="01" width="200">"#));
+		assert!(output.contains("<words> dolce </words>"));
+		assert!(output.contains(r#"<sound tempo="120"/>"#));
+		assert!(output.contains("<text> </text>"));
+		assert!(output.contains("<rest/>"));
+		assert!(reformat("<a><b></b></a>", &ReformatOptions::default()).unwrap().contains("<b/>"));
+	}
+
+	#[test]
+	fn reformat_is_idempotent() {
+		let options = ReformatOptions {
+			indent: 4,
+			strip_layout: true,
+			..ReformatOptions::default()
+		};
+		let once = reformat(SCORE, &options).unwrap();
+		assert_eq!(reformat(&once, &options).unwrap(), once);
+	}
+}
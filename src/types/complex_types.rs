@@ -1,45 +1,5117 @@
-use serde::Deserialize;
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::vec;
+use serde::{de, Deserialize, Deserializer};
 use crate::types::*;
+use crate::duration::Duration;
 
 #[derive(Debug, Deserialize, PartialEq)]
-struct Accidental {
-	cautionary: Option<YesNo>,
-	editorial: Option<YesNo>,
-	bracket: Option<YesNo>,
-	size: Option<SymbolSize>,
+pub struct Accidental {
+	pub cautionary: Option<YesNo>,
+	pub editorial: Option<YesNo>,
+	pub bracket: Option<YesNo>,
+	pub size: Option<SymbolSize>,
 	#[serde(rename = "default-x")]
-	default_x: Option<Tenths>,
+	pub default_x: Option<Tenths>,
 	#[serde(rename = "default-y")]
-	default_y: Option<Tenths>,
+	pub default_y: Option<Tenths>,
 	#[serde(rename = "font-family")]
-	font_family: Option<CommaSeparatedText>,
+	pub font_family: Option<CommaSeparatedText>,
 	#[serde(rename = "font-style")]
-	font_style: Option<FontStyle>,
+	pub font_style: Option<FontStyle>,
 	#[serde(rename = "font-size")]
-	font_size: Option<FontSize>,
+	pub font_size: Option<FontSize>,
 	#[serde(rename = "font-weight")]
-	font_weight: Option<FontWeight>,
-	color: Option<Color>,
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub smufl: Option<SmuflGlyphName>,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
-struct AccidentalMark {
+pub struct AccidentalMark {
 	#[serde(rename = "default-x")]
-	default_x: Option<Tenths>,
+	pub default_x: Option<Tenths>,
 	#[serde(rename = "default-y")]
-	default_y: Option<Tenths>,
+	pub default_y: Option<Tenths>,
 	#[serde(rename = "relative-x")]
-	relative_x: Option<Tenths>,
+	pub relative_x: Option<Tenths>,
 	#[serde(rename = "relative-y")]
-	relative_y: Option<Tenths>,
+	pub relative_y: Option<Tenths>,
 	#[serde(rename = "font-family")]
-	font_family: Option<CommaSeparatedText>,
+	pub font_family: Option<CommaSeparatedText>,
 	#[serde(rename = "font-style")]
-	font_style: Option<FontStyle>,
+	pub font_style: Option<FontStyle>,
 	#[serde(rename = "font-size")]
-	font_size: Option<FontSize>,
+	pub font_size: Option<FontSize>,
 	#[serde(rename = "font-weight")]
-	font_weight: Option<FontWeight>,
-	color: Option<Color>,
-	placement: Option<AboveBelow>,
-}
\ No newline at end of file
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+	pub smufl: Option<SmuflGlyphName>,
+}
+
+/// The beater type represents pictograms for beaters, mallets, and sticks that do not have different materials represented in the pictogram.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Beater {
+	#[serde(rename = "$value")]
+	pub value: BeaterValue,
+	pub tip: Option<TipDirection>,
+}
+
+/// The stick type represents pictograms where the material of the stick, mallet, or beater is included. The parentheses and dashed-circle attributes indicate the presence of these marks around the round beater part of a pictogram. Values for these attributes are "no" if not present.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Stick {
+	#[serde(rename = "stick-type")]
+	pub stick_type: StickType,
+	#[serde(rename = "stick-material")]
+	pub stick_material: StickMaterial,
+	pub tip: Option<TipDirection>,
+	pub parentheses: Option<YesNo>,
+	#[serde(rename = "dashed-circle")]
+	pub dashed_circle: Option<YesNo>,
+}
+
+impl Stick {
+	/// Returns whether parentheses appear around the beater part of the pictogram, which is no if not specified.
+	pub fn parentheses(&self) -> YesNo {
+		self.parentheses.unwrap_or(YesNo::No)
+	}
+
+	/// Returns whether a dashed circle appears around the beater part of the pictogram, which is no if not specified.
+	pub fn dashed_circle(&self) -> YesNo {
+		self.dashed_circle.unwrap_or(YesNo::No)
+	}
+}
+
+/// The other-text type represents a text element with a smufl attribute group. This type is used by MusicXML direction extension elements to allow specification of specific SMuFL glyphs without needed to add every glyph as a MusicXML element.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct OtherText {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	pub smufl: Option<SmuflGlyphName>,
+}
+
+/// The glass type represents pictograms for glass percussion instruments. The smufl attribute is used to distinguish different SMuFL glyphs for wind chimes in the Chimes pictograms range, including those made of materials other than glass.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Glass {
+	#[serde(rename = "$value")]
+	pub value: GlassValue,
+	pub smufl: Option<SmuflGlyphName>,
+}
+
+/// The metal type represents pictograms for metal percussion instruments. The smufl attribute is used to distinguish different SMuFL stylistic alternates.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Metal {
+	#[serde(rename = "$value")]
+	pub value: MetalValue,
+	pub smufl: Option<SmuflGlyphName>,
+}
+
+/// The wood type represents pictograms for wood percussion instruments. The smufl attribute is used to distinguish different SMuFL stylistic alternates.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Wood {
+	#[serde(rename = "$value")]
+	pub value: WoodValue,
+	pub smufl: Option<SmuflGlyphName>,
+}
+
+/// The pitched type represents pictograms for pitched percussion instruments. The smufl attribute is used to distinguish different SMuFL glyphs for a particular pictogram within the Tuned mallet percussion pictograms range.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Pitched {
+	#[serde(rename = "$value")]
+	pub value: PitchedValue,
+	pub smufl: Option<SmuflGlyphName>,
+}
+
+/// The membrane type represents pictograms for membrane percussion instruments. The smufl attribute is used to distinguish different SMuFL stylistic alternates.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Membrane {
+	#[serde(rename = "$value")]
+	pub value: MembraneValue,
+	pub smufl: Option<SmuflGlyphName>,
+}
+
+/// The effect type represents pictograms for sound effect percussion instruments. The smufl attribute is used to distinguish different SMuFL stylistic alternates.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Effect {
+	#[serde(rename = "$value")]
+	pub value: EffectValue,
+	pub smufl: Option<SmuflGlyphName>,
+}
+
+/// The timpani type represents the timpani pictogram. The smufl attribute is used to distinguish different SMuFL stylistic alternates.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Timpani {
+	pub smufl: Option<SmuflGlyphName>,
+}
+
+/// The pictogram chosen inside a percussion element.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum PercussionPictogram {
+	Glass(Glass),
+	Metal(Metal),
+	Wood(Wood),
+	Pitched(Pitched),
+	Membrane(Membrane),
+	Effect(Effect),
+	Timpani(Timpani),
+	Beater(Beater),
+	Stick(Stick),
+	StickLocation(StickLocation),
+	OtherPercussion(OtherText),
+}
+
+/// The percussion element is used to define percussion pictogram symbols. Definitions for these symbols can be found in Kurt Stone's "Music Notation in the Twentieth Century" on pages 206-212 and 223. Some values are added to these based on how usage has evolved in the 30 years since Stone's book was published.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Percussion {
+	#[serde(rename = "$value")]
+	pub pictogram: PercussionPictogram,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+	pub enclosure: Option<EnclosureShape>,
+	pub smufl: Option<SmuflGlyphName>,
+	pub id: Option<String>,
+}
+
+/// The hole-closed type represents whether the hole is closed, open, or half-open. The optional location attribute indicates which portion of the hole is filled in when the element value is half.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct HoleClosed {
+	#[serde(rename = "$value")]
+	pub value: HoleClosedValue,
+	pub location: Option<HoleClosedLocation>,
+}
+
+/// The hole type represents the symbols used for woodwind and brass fingerings as well as other notations. The content of the optional hole-type element indicates what the hole symbol represents in terms of instrument fingering or other techniques. The optional hole-shape element indicates the shape of the hole symbol; the default is a circle.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Hole {
+	#[serde(rename = "hole-type")]
+	pub hole_type: Option<String>,
+	#[serde(rename = "hole-closed")]
+	pub hole_closed: HoleClosed,
+	#[serde(rename = "hole-shape")]
+	pub hole_shape: Option<String>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+}
+
+/// The handbell element represents notation for various techniques used in handbell and handchime music.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Handbell {
+	#[serde(rename = "$value")]
+	pub value: HandbellValue,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+}
+
+/// The empty type represents an empty element with no attributes.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Empty {}
+
+/// The arrow element represents an arrow used for a musical technical indication. It can represent both Unicode and SMuFL arrows. The presence of an arrowhead element indicates that only the arrowhead is displayed, not the arrow stem. The smufl attribute distinguishes different SMuFL glyphs that have an arrow appearance such as arrowBlackUp, guitarStrumUp, or handbellsSwingUp. The specified glyph should match the descriptive representation.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Arrow {
+	#[serde(rename = "arrow-direction")]
+	pub arrow_direction: Option<ArrowDirection>,
+	#[serde(rename = "arrow-style")]
+	pub arrow_style: Option<ArrowStyle>,
+	pub arrowhead: Option<Empty>,
+	#[serde(rename = "circular-arrow")]
+	pub circular_arrow: Option<CircularArrow>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+	pub smufl: Option<SmuflGlyphName>,
+}
+
+/// The largest number of notes, steps or beats that a helper expands a single element into. Attribute values asking for more are treated as invalid instead of being allocated.
+const MAX_EXPANSION: usize = 1024;
+
+/// The trill-sound attribute values used for playback of trills, mordents and turns. The Default implementation gives the values that MusicXML implies when the attributes are absent; applications can supply their own defaults when realizing ornaments.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrillSound {
+	pub start_note: StartNote,
+	pub trill_step: TrillStep,
+	pub two_note_turn: TwoNoteTurn,
+	pub accelerate: YesNo,
+	pub beats: TrillBeats,
+	pub second_beat: Percent,
+	pub last_beat: Percent,
+}
+
+impl Default for TrillSound {
+	fn default() -> Self {
+		TrillSound {
+			start_note: StartNote::Upper,
+			trill_step: TrillStep::Whole,
+			two_note_turn: TwoNoteTurn::None,
+			accelerate: YesNo::No,
+			beats: 4.0,
+			second_beat: 25,
+			last_beat: 75,
+		}
+	}
+}
+
+/// A note played when realizing an ornament, relative to the ornamented note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrnamentNote {
+	Main,
+	Upper,
+	Lower,
+}
+
+impl TrillSound {
+	/// Returns the interval in semitones between the main note and the upper note of the trill.
+	pub fn upper_interval(&self) -> Semitones {
+		match self.trill_step {
+			TrillStep::Whole => 2.0,
+			TrillStep::Half => 1.0,
+			TrillStep::Unison => 0.0,
+		}
+	}
+
+	/// Returns the interval in semitones between the main note and the lower note of the two-note turn, or None if the trill ends without one.
+	pub fn turn_interval(&self) -> Option<Semitones> {
+		match self.two_note_turn {
+			TwoNoteTurn::Whole => Some(2.0),
+			TwoNoteTurn::Half => Some(1.0),
+			TwoNoteTurn::None => None,
+		}
+	}
+
+	/// Realizes a trill as the notes played, each paired with the fraction of the ornamented note's duration at which it starts. The trill alternates between the main and upper notes for the number of beats, starting on the start note; a trill starting below plays the lower note first. The second note starts at second-beat percent of the duration and the last at last-beat percent, with the notes in between getting closer together when accelerate is yes. A two-note turn adds the lower note and the main note, evenly spaced after the last trill note. Returns None if the number of beats is too large to realize.
+	pub fn realize(&self) -> Option<Vec<(f64, OrnamentNote)>> {
+		let beats = self.beats.max(2.0).round();
+		if beats > MAX_EXPANSION as f64 {
+			return None;
+		}
+		let beats = beats as usize;
+		let second = f64::from(self.second_beat) / 100.0;
+		let last = f64::from(self.last_beat) / 100.0;
+		let mut notes: Vec<(f64, OrnamentNote)> = (0..beats)
+			.map(|beat| {
+				let start = if beat == 0 {
+					0.0
+				} else {
+					let progress = if beats > 2 { (beat - 1) as f64 / (beats - 2) as f64 } else { 0.0 };
+					let time = if self.accelerate == YesNo::Yes { progress.sqrt() } else { progress };
+					second + (last - second) * time
+				};
+				let note = match (self.start_note, beat % 2) {
+					(StartNote::Below, _) if beat == 0 => OrnamentNote::Lower,
+					(StartNote::Main, 0) | (StartNote::Upper, 1) | (StartNote::Below, 1) => OrnamentNote::Main,
+					_ => OrnamentNote::Upper,
+				};
+				(start, note)
+			})
+			.collect();
+		if self.turn_interval().is_some() {
+			let start = notes.last().map_or(0.0, |&(start, _)| start);
+			let step = (1.0 - start) / 3.0;
+			notes.push((start + step, OrnamentNote::Lower));
+			notes.push((start + 2.0 * step, OrnamentNote::Main));
+		}
+		Some(notes)
+	}
+}
+
+/// The empty-trill-sound type represents an empty element with print-style, placement, and trill-sound attributes.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct EmptyTrillSound {
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+	#[serde(rename = "start-note")]
+	pub start_note: Option<StartNote>,
+	#[serde(rename = "trill-step")]
+	pub trill_step: Option<TrillStep>,
+	#[serde(rename = "two-note-turn")]
+	pub two_note_turn: Option<TwoNoteTurn>,
+	pub accelerate: Option<YesNo>,
+	pub beats: Option<TrillBeats>,
+	#[serde(rename = "second-beat")]
+	pub second_beat: Option<Percent>,
+	#[serde(rename = "last-beat")]
+	pub last_beat: Option<Percent>,
+}
+
+impl EmptyTrillSound {
+	/// Returns the trill-sound values of this element, taking any absent attribute from `defaults`.
+	pub fn trill_sound(&self, defaults: &TrillSound) -> TrillSound {
+		TrillSound {
+			start_note: self.start_note.unwrap_or(defaults.start_note),
+			trill_step: self.trill_step.unwrap_or(defaults.trill_step),
+			two_note_turn: self.two_note_turn.unwrap_or(defaults.two_note_turn),
+			accelerate: self.accelerate.unwrap_or(defaults.accelerate),
+			beats: self.beats.unwrap_or(defaults.beats),
+			second_beat: self.second_beat.unwrap_or(defaults.second_beat),
+			last_beat: self.last_beat.unwrap_or(defaults.last_beat),
+		}
+	}
+}
+
+/// The link type serves as an outgoing simple XLink. If a relative link is used within a document that is part of a compressed MusicXML file, the link is relative to the root folder of the zip file.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Link {
+	#[serde(rename = "xlink:href")]
+	pub href: String,
+	#[serde(rename = "xlink:type")]
+	pub link_type: Option<String>,
+	#[serde(rename = "xlink:role")]
+	pub role: Option<String>,
+	#[serde(rename = "xlink:title")]
+	pub title: Option<String>,
+	#[serde(rename = "xlink:show")]
+	pub show: Option<XlinkShow>,
+	#[serde(rename = "xlink:actuate")]
+	pub actuate: Option<XlinkActuate>,
+	pub name: Option<String>,
+	pub element: Option<String>,
+	pub position: Option<u64>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+}
+
+impl Link {
+	/// Returns the xlink:show value, which is replace if not specified.
+	pub fn show(&self) -> XlinkShow {
+		self.show.unwrap_or_default()
+	}
+
+	/// Returns the xlink:actuate value, which is onRequest if not specified.
+	pub fn actuate(&self) -> XlinkActuate {
+		self.actuate.unwrap_or_default()
+	}
+
+	/// Returns the id targeted by a link within the same document, written as a fragment such as "#coda", or None for links to other resources.
+	pub fn target_id(&self) -> Option<&str> {
+		self.href.strip_prefix('#').filter(|id| !id.is_empty())
+	}
+}
+
+/// The bookmark type serves as a well-defined target for an incoming simple XLink.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Bookmark {
+	pub id: String,
+	pub name: Option<String>,
+	pub element: Option<String>,
+	pub position: Option<u64>,
+}
+
+/// Indexes the id attributes of a document, such as those of bookmarks, in document order, so that link targets can be resolved. MusicXML ids must be unique within a document; repeated ids are recorded and resolve to their first occurrence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdIndex {
+	positions: HashMap<String, usize>,
+	duplicates: Vec<String>,
+}
+
+impl IdIndex {
+	/// Creates an index from ids in document order.
+	pub fn new<'a, I>(ids: I) -> IdIndex
+	where
+		I: IntoIterator<Item = &'a str>,
+	{
+		let mut positions = HashMap::new();
+		let mut duplicates = Vec::new();
+		for (position, id) in ids.into_iter().enumerate() {
+			if positions.contains_key(id) {
+				duplicates.push(id.to_string());
+			} else {
+				positions.insert(id.to_string(), position);
+			}
+		}
+		IdIndex { positions, duplicates }
+	}
+
+	/// Returns the position in document order of the first element with the given id.
+	pub fn resolve(&self, id: &str) -> Option<usize> {
+		self.positions.get(id).copied()
+	}
+
+	/// Returns the ids that appear more than once, once for each repeated occurrence.
+	pub fn duplicates(&self) -> &[String] {
+		&self.duplicates
+	}
+
+	/// Returns the links that target an id within the document that does not exist.
+	pub fn dangling<'a, I>(&self, links: I) -> Vec<&'a Link>
+	where
+		I: IntoIterator<Item = &'a Link>,
+	{
+		links
+			.into_iter()
+			.filter(|link| link.target_id().is_some_and(|id| self.resolve(id).is_none()))
+			.collect()
+	}
+}
+
+/// A cancel element indicates that the old key signature should be cancelled before the new one appears. This will always happen when changing to C major or A minor and need not be specified then. The cancel value matches the fifths value of the cancelled key signature (e.g., a cancel of -2 will provide an explicit cancellation for changing from B flat major to F major). The optional location attribute indicates where the cancellation appears relative to the new key signature.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Cancel {
+	#[serde(rename = "$value")]
+	pub fifths: Fifths,
+	pub location: Option<CancelLocation>,
+}
+
+/// The key-octave element specifies in which octave an element of a key signature appears. The content specifies the octave value using the same values as the display-octave element. The number attribute is a positive integer that refers to the key signature element in left-to-right order. If the cancel attribute is set to yes, then this number refers to the canceled key signature specified by the cancel element in the parent key element. The cancel attribute cannot be set to yes if there is no corresponding cancel element within the parent key element. It is no by default.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct KeyOctave {
+	#[serde(rename = "$value")]
+	pub octave: Octave,
+	pub number: u64,
+	pub cancel: Option<YesNo>,
+}
+
+/// The key type represents a key signature. Both traditional and non-traditional key signatures are supported. The optional number attribute refers to staff numbers. If absent, the key signature applies to all staves in the part. Key signatures appear at the start of each system unless the print-object attribute has been set to "no".
+///
+/// Only traditional key signatures (cancel, fifths, and mode) are modelled so far.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Key {
+	pub cancel: Option<Cancel>,
+	pub fifths: Fifths,
+	pub mode: Option<Mode>,
+	#[serde(rename = "key-octave", default)]
+	pub key_octave: Vec<KeyOctave>,
+	pub number: Option<StaffNumber>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	#[serde(rename = "print-object")]
+	pub print_object: Option<YesNo>,
+	pub id: Option<String>,
+}
+
+/// Steps in the order in which sharps are added to key signatures, which is also their order along the line of fifths starting from F.
+const STEPS_BY_FIFTHS: [Step; 7] = [Step::F, Step::C, Step::G, Step::D, Step::A, Step::E, Step::B];
+
+/// Returns the distance in fifths from the tonic of a major key to the tonic of the given mode with the same key signature.
+fn mode_offset(mode: &Mode) -> Option<Fifths> {
+	match mode {
+		Mode::Lydian => Some(-1),
+		Mode::Major | Mode::Ionian => Some(0),
+		Mode::Mixolydian => Some(1),
+		Mode::Dorian => Some(2),
+		Mode::Minor | Mode::Aeolian => Some(3),
+		Mode::Phrygian => Some(4),
+		Mode::Locrian => Some(5),
+		_ => None,
+	}
+}
+
+impl Key {
+	/// Creates a traditional key signature from its tonic and mode. Returns None for microtonal alterations and for modes other than major, minor, and the church modes.
+	pub fn from_tonic(step: Step, alter: Semitones, mode: Mode) -> Option<Key> {
+		if alter.fract() != 0.0 {
+			return None;
+		}
+		let position = STEPS_BY_FIFTHS.iter().position(|&s| s == step)? as Fifths - 1 + 7 * alter as Fifths;
+		let fifths = position - mode_offset(&mode)?;
+		Some(Key::from_fifths(fifths, Some(mode)))
+	}
+
+	/// Creates a traditional key signature with the given number of flats or sharps and an optional mode.
+	pub fn from_fifths(fifths: Fifths, mode: Option<Mode>) -> Key {
+		Key {
+			cancel: None,
+			fifths,
+			mode,
+			key_octave: Vec::new(),
+			number: None,
+			default_x: None,
+			default_y: None,
+			relative_x: None,
+			relative_y: None,
+			font_family: None,
+			font_style: None,
+			font_size: None,
+			font_weight: None,
+			color: None,
+			print_object: None,
+			id: None,
+		}
+	}
+
+	/// Returns the tonic of this key signature in the given mode as a step and chromatic alteration, or None if the mode has no tonic (such as none).
+	pub fn tonic(&self, mode: &Mode) -> Option<(Step, Semitones)> {
+		let position = self.fifths + mode_offset(mode)? + 1;
+		let step = STEPS_BY_FIFTHS[position.rem_euclid(7) as usize];
+		Some((step, position.div_euclid(7) as Semitones))
+	}
+
+	/// Returns the chromatic alteration the key signature applies to the given step: 1 for a sharp, -1 for a flat, 2 for a double sharp, and so on.
+	pub fn accidental_for(&self, step: Step) -> Semitones {
+		let index = STEPS_BY_FIFTHS.iter().position(|&s| s == step).unwrap() as Fifths;
+		if self.fifths > 0 {
+			((self.fifths - index + 6) / 7) as Semitones
+		} else {
+			-((-self.fifths - (6 - index) + 6) / 7) as Semitones
+		}
+	}
+
+	/// Returns the steps altered by the key signature with their alterations, in the order the accidentals are written.
+	pub fn altered_steps(&self) -> Vec<(Step, Semitones)> {
+		let count = self.fifths.abs().min(7) as usize;
+		let mut steps: Vec<Step> = STEPS_BY_FIFTHS.to_vec();
+		if self.fifths < 0 {
+			steps.reverse();
+		}
+		steps.into_iter().take(count).map(|step| (step, self.accidental_for(step))).collect()
+	}
+}
+
+/// Time signatures are represented by the beats element for the numerator and the beat-type element for the denominator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeSignature {
+	pub beats: String,
+	pub beat_type: String,
+}
+
+impl TimeSignature {
+	/// Returns the number of beat-type units in the numerator, adding up the terms of an additive numerator such as 3+2. Returns None if a term is not a number or the total does not fit.
+	pub fn beat_count(&self) -> Option<u32> {
+		self.beats.split('+').try_fold(0u32, |total, beats| total.checked_add(beats.trim().parse().ok()?))
+	}
+
+	/// Returns whether the numerator is a compound meter, such as 6 or 12, whose beats divide into dotted groups of three. Additive numerators are never compound.
+	pub fn is_compound(&self) -> bool {
+		!self.beats.contains('+') && self.beat_count().is_some_and(|beats| beats > 3 && beats.is_multiple_of(3))
+	}
+
+	/// Returns the beat groups of the numerator, in beat-type units. An additive numerator such as 3+2 gives one group per term, a compound numerator such as 6 gives dotted groups of three, and any other numerator gives one group per beat. Returns None if the beats are not numbers or there are too many groups to list.
+	pub fn beat_groups(&self) -> Option<Vec<u32>> {
+		let beats = self.beat_count()?;
+		if self.beats.contains('+') {
+			return self.beats.split('+').map(|beats| beats.trim().parse().ok()).collect();
+		}
+		let (group, count) = if self.is_compound() { (3, beats / 3) } else { (1, beats) };
+		if count as usize > MAX_EXPANSION {
+			return None;
+		}
+		Some(vec![group; count as usize])
+	}
+
+	/// Returns the length of the given number of beat-type units, or None if the beat-type is not a positive number.
+	fn units(&self, count: u32) -> Option<Duration> {
+		let beat_type: u64 = self.beat_type.trim().parse().ok()?;
+		Duration::checked_new(u64::from(count), beat_type)
+	}
+
+	/// Returns the length of a measure in this time signature, or None if the beats or beat-type are not numbers.
+	pub fn measure_duration(&self) -> Option<Duration> {
+		self.units(self.beat_count()?)
+	}
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum TimeElement {
+	Beats(String),
+	BeatType(String),
+	TimeRelation(TimeRelation),
+	Interchangeable(Interchangeable),
+	SenzaMisura(String),
+}
+
+/// Pairs up the beats and beat-type elements of a time or interchangeable element.
+fn time_signatures<E: de::Error>(elements: &mut Vec<TimeElement>) -> Result<Vec<TimeSignature>, E> {
+	let mut signatures = Vec::new();
+	let mut beats = None;
+	for element in elements.drain(..) {
+		match (element, beats.take()) {
+			(TimeElement::Beats(value), None) => beats = Some(value),
+			(TimeElement::BeatType(beat_type), Some(beats)) => signatures.push(TimeSignature { beats, beat_type }),
+			_ => return Err(de::Error::custom("beats and beat-type elements must alternate")),
+		}
+	}
+	if beats.is_some() {
+		return Err(de::Error::custom("beats element without beat-type"));
+	}
+	Ok(signatures)
+}
+
+#[derive(Deserialize)]
+struct RawInterchangeable {
+	#[serde(rename = "$value")]
+	elements: Vec<TimeElement>,
+	symbol: Option<TimeSymbol>,
+	separator: Option<TimeSeparator>,
+}
+
+/// The interchangeable type is used to represent the second in a pair of interchangeable dual time signatures, such as the 6/8 in 3/4 (6/8). A separate symbol attribute value is available compared to the time element's symbol attribute, which applies to the first of the dual time signatures.
+#[derive(Debug, PartialEq)]
+pub struct Interchangeable {
+	pub time_relation: Option<TimeRelation>,
+	pub signatures: Vec<TimeSignature>,
+	pub symbol: Option<TimeSymbol>,
+	pub separator: Option<TimeSeparator>,
+}
+
+impl<'de> Deserialize<'de> for Interchangeable {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let mut raw = RawInterchangeable::deserialize(deserializer)?;
+		let time_relation = match raw.elements.first() {
+			Some(TimeElement::TimeRelation(relation)) => Some(*relation),
+			_ => None,
+		};
+		if time_relation.is_some() {
+			raw.elements.remove(0);
+		}
+		Ok(Interchangeable {
+			time_relation,
+			signatures: time_signatures(&mut raw.elements)?,
+			symbol: raw.symbol,
+			separator: raw.separator,
+		})
+	}
+}
+
+/// The content of a time element: either one or more time signatures, optionally followed by an interchangeable time signature, or a senza-misura element. A senza-misura element explicitly indicates that no time signature is present. The optional element content indicates the symbol to be used, if any, such as an X.
+#[derive(Debug, PartialEq)]
+pub enum TimeContent {
+	Signatures {
+		signatures: Vec<TimeSignature>,
+		interchangeable: Option<Interchangeable>,
+	},
+	SenzaMisura(String),
+}
+
+impl<'de> Deserialize<'de> for TimeContent {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let mut elements = Vec::<TimeElement>::deserialize(deserializer)?;
+		if let Some(TimeElement::SenzaMisura(_)) = elements.first() {
+			if let TimeElement::SenzaMisura(symbol) = elements.remove(0) {
+				return Ok(TimeContent::SenzaMisura(symbol));
+			}
+		}
+		let interchangeable = match elements.last() {
+			Some(TimeElement::Interchangeable(_)) => match elements.pop() {
+				Some(TimeElement::Interchangeable(interchangeable)) => Some(interchangeable),
+				_ => None,
+			},
+			_ => None,
+		};
+		let signatures = time_signatures(&mut elements)?;
+		if signatures.is_empty() {
+			return Err(de::Error::custom("time element without a time signature"));
+		}
+		Ok(TimeContent::Signatures {
+			signatures,
+			interchangeable,
+		})
+	}
+}
+
+/// Time signatures are represented by the beats element for the numerator and the beat-type element for the denominator. The symbol attribute is used to indicate common and cut time symbols as well as a single number display. Multiple pairs of beat and beat-type elements are used for composite time signatures with multiple denominators, such as 2/4 + 3/8. A composite such as 3+2/8 requires only one beat/beat-type pair. The print-object attribute allows a time signature to be specified but not printed, as is the case for excerpts from the middle of a score. The value is "yes" if not present. The optional number attribute refers to staff numbers within the part. If absent, the time signature applies to all staves in the part.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Time {
+	#[serde(rename = "$value")]
+	pub content: TimeContent,
+	pub number: Option<StaffNumber>,
+	pub symbol: Option<TimeSymbol>,
+	pub separator: Option<TimeSeparator>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+	#[serde(rename = "print-object")]
+	pub print_object: Option<YesNo>,
+	pub id: Option<String>,
+}
+
+impl Time {
+	/// Returns the time signatures, or an empty slice for senza-misura.
+	pub fn signatures(&self) -> &[TimeSignature] {
+		match &self.content {
+			TimeContent::Signatures { signatures, .. } => signatures,
+			TimeContent::SenzaMisura(_) => &[],
+		}
+	}
+
+	/// Returns the length of a measure, or None for senza-misura and for non-numeric beats or beat-types.
+	pub fn measure_duration(&self) -> Option<Duration> {
+		let signatures = self.signatures();
+		if signatures.is_empty() {
+			return None;
+		}
+		signatures.iter().try_fold(Duration::ZERO, |total, signature| total.checked_add(signature.measure_duration()?))
+	}
+
+	/// Returns the length of a measure in divisions, given the number of divisions per quarter note. Returns None if the measure has no fixed length or it is not a whole number of divisions.
+	pub fn measure_length(&self, divisions: u64) -> Option<u64> {
+		self.measure_duration()?.to_divisions(divisions)
+	}
+
+	/// Returns the offsets of the beats from the start of the measure. Compound meters have one beat per dotted group and additive meters such as 3+2/8 have one beat per term. Returns an empty list if the beats cannot be listed.
+	pub fn beat_positions(&self) -> Vec<Duration> {
+		let mut positions = Vec::new();
+		let mut position = Duration::ZERO;
+		for signature in self.signatures() {
+			let groups = match signature.beat_groups() {
+				Some(groups) => groups,
+				None => return Vec::new(),
+			};
+			for group in groups {
+				positions.push(position);
+				position = match signature.units(group).and_then(|length| position.checked_add(length)) {
+					Some(position) => position,
+					None => return Vec::new(),
+				};
+			}
+		}
+		positions
+	}
+
+	/// Returns whether the time signature is a single compound meter, such as 6/8 or 12/16, whose beats divide into three.
+	pub fn is_compound(&self) -> bool {
+		match self.signatures() {
+			[signature] => signature.is_compound(),
+			_ => false,
+		}
+	}
+}
+
+/// Pitch is represented as a combination of the step of the diatonic scale, the chromatic alteration, and the octave.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Pitch {
+	pub step: Step,
+	pub alter: Option<Semitones>,
+	pub octave: Octave,
+}
+
+impl Pitch {
+	/// Returns the number of diatonic steps from C in octave 0 to this pitch, ignoring the alteration.
+	pub fn diatonic_number(&self) -> i64 {
+		i64::from(self.octave) * 7 + self.step.index()
+	}
+
+	/// Returns the number of semitones from C in octave 0 to this pitch, including the alteration.
+	pub fn semitone_number(&self) -> Semitones {
+		(i64::from(self.octave) * 12 + self.step.semitone()) as Semitones + self.alter.unwrap_or(0.0)
+	}
+}
+
+/// Clefs are represented by a combination of sign, line, and clef-octave-change elements. The optional number attribute refers to staff numbers within the part. A value of 1 is assumed if not present.
+///
+/// Sometimes clefs are added to the staff in non-standard line positions, either to indicate cue passages, or when there are multiple clefs present simultaneously on one staff. In this situation, the additional attribute is set to "yes" and the line value is ignored. The size attribute is used for clefs where the additional attribute is "yes". It is typically used to indicate cue clefs.
+///
+/// Sometimes clefs at the start of a measure need to appear after the barline rather than before, as for cues or for use after a repeated section. The after-barline attribute is set to "yes" in this situation. The attribute is ignored for mid-measure clefs.
+///
+/// Clefs appear at the start of each system unless the print-object attribute has been set to "no" or the additional attribute has been set to "yes".
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Clef {
+	pub sign: ClefSign,
+	pub line: Option<StaffLine>,
+	#[serde(rename = "clef-octave-change")]
+	pub clef_octave_change: Option<i64>,
+	pub number: Option<StaffNumber>,
+	pub additional: Option<YesNo>,
+	pub size: Option<SymbolSize>,
+	#[serde(rename = "after-barline")]
+	pub after_barline: Option<YesNo>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	#[serde(rename = "print-object")]
+	pub print_object: Option<YesNo>,
+	pub id: Option<String>,
+}
+
+impl Clef {
+	/// Returns the staff line the clef sits on, using the standard line for the sign when the line element is absent.
+	pub fn staff_line(&self) -> Option<StaffLine> {
+		match (self.line, self.sign) {
+			(Some(line), _) => Some(line),
+			(None, ClefSign::G) => Some(2),
+			(None, ClefSign::F) => Some(4),
+			(None, ClefSign::C) => Some(3),
+			_ => None,
+		}
+	}
+
+	/// Returns the vertical position of a pitch on the staff, in staff steps above the bottom line: 0 is the bottom line, 1 the space above it, 2 the second line, and so on. Negative values are below the staff. Returns None for clefs without a pitch reference, such as percussion and TAB.
+	pub fn staff_position(&self, pitch: &Pitch) -> Option<i64> {
+		let (step, octave) = match self.sign {
+			ClefSign::G => (Step::G, 4),
+			ClefSign::F => (Step::F, 3),
+			ClefSign::C => (Step::C, 4),
+			_ => return None,
+		};
+		let reference = Pitch {
+			step,
+			alter: None,
+			octave,
+		};
+		let octave_change = self.clef_octave_change.unwrap_or(0) * 7;
+		let line = self.staff_line()?;
+		Some(pitch.diatonic_number() - reference.diatonic_number() - octave_change + (line - 1) * 2)
+	}
+}
+
+/// The staff-tuning type specifies the open, non-capo tuning of the lines on a tablature staff.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct StaffTuning {
+	#[serde(rename = "tuning-step")]
+	pub tuning_step: Step,
+	#[serde(rename = "tuning-alter")]
+	pub tuning_alter: Option<Semitones>,
+	#[serde(rename = "tuning-octave")]
+	pub tuning_octave: Octave,
+	pub line: StaffLine,
+}
+
+impl StaffTuning {
+	/// Returns the open pitch of the staff line.
+	pub fn pitch(&self) -> Pitch {
+		Pitch {
+			step: self.tuning_step,
+			alter: self.tuning_alter,
+			octave: self.tuning_octave,
+		}
+	}
+}
+
+/// If the staff-lines element is present, the appearance of each line may be individually specified with a line-detail type. Staff lines are numbered from bottom to top. The print-object attribute allows lines to be hidden within a staff. This is used in special situations such as a widely-spaced percussion staff where a note placed below the higher line is distinct from a note placed above the lower line. Hidden staff lines are included when specifying clef lines and determining display-step / display-octave values, but are not counted as lines for the purposes of the system-layout and staff-layout elements.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct LineDetail {
+	pub line: StaffLine,
+	pub width: Option<Tenths>,
+	pub color: Option<Color>,
+	#[serde(rename = "line-type")]
+	pub line_type: Option<LineType>,
+	#[serde(rename = "print-object")]
+	pub print_object: Option<YesNo>,
+}
+
+/// The staff-size element indicates how large a staff space is on this staff, expressed as a percentage of the work's default scaling. Values less than 100 make the staff space smaller while values over 100 make the staff space larger. A staff-type of cue, ossia, or editorial implies a staff-size of less than 100, but the exact value is implementation-dependent unless specified here. Staff size affects staff height only, not the relationship of the staff to the left and right margins.
+///
+/// In some cases, a staff-size different than 100 also scales the notation on the staff, such as with a cue staff. In other cases, such as percussion staves, the lines may be more widely spaced without scaling the notation on the staff. The scaling attribute allows these two cases to be distinguished. It specifies the percentage scaling that applies to the notation. Values less that 100 make the notation smaller while values over 100 make the notation larger. The staff-size content and scaling attribute are both non-negative decimal values.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct StaffSize {
+	#[serde(rename = "$value")]
+	pub value: NonNegativeDecimal,
+	pub scaling: Option<NonNegativeDecimal>,
+}
+
+/// The staff-details element is used to indicate different types of staves. The optional number attribute specifies the staff number from top to bottom on the system, as with clef. The print-object attribute is used to indicate when a staff is not printed in a part, usually in large scores where empty parts are omitted. It is yes by default. If print-spacing is yes while print-object is no, the score is printed in cutaway format where vertical space is left for the empty part.
+///
+/// The staff-lines element specifies the number of lines and is usually used for a non 5-line staff. If the staff-lines element is present, the appearance of each line may be individually specified with a line-detail element. The staff-tuning and capo elements are used to specify tuning when using tablature notation. The capo element indicates at which fret a capo should be placed on a fretted instrument.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct StaffDetails {
+	#[serde(rename = "staff-type")]
+	pub staff_type: Option<StaffType>,
+	#[serde(rename = "staff-lines")]
+	pub staff_lines: Option<u64>,
+	#[serde(rename = "line-detail", default)]
+	pub line_detail: Vec<LineDetail>,
+	#[serde(rename = "staff-tuning", default)]
+	pub staff_tuning: Vec<StaffTuning>,
+	pub capo: Option<u64>,
+	#[serde(rename = "staff-size")]
+	pub staff_size: Option<StaffSize>,
+	pub number: Option<StaffNumber>,
+	#[serde(rename = "show-frets")]
+	pub show_frets: Option<ShowFrets>,
+	#[serde(rename = "print-object")]
+	pub print_object: Option<YesNo>,
+	#[serde(rename = "print-spacing")]
+	pub print_spacing: Option<YesNo>,
+}
+
+/// The staff details in effect at some point in a part, accumulated from the staff-details elements that apply to one staff. Each staff-details element only changes the values it specifies.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaffConfig {
+	pub staff_type: StaffType,
+	pub staff_lines: u64,
+	pub line_detail: Vec<LineDetail>,
+	pub staff_tuning: Vec<StaffTuning>,
+	pub capo: u64,
+	pub staff_size: Option<StaffSize>,
+	pub show_frets: ShowFrets,
+	pub print_object: YesNo,
+}
+
+impl Default for StaffConfig {
+	fn default() -> Self {
+		StaffConfig {
+			staff_type: StaffType::Regular,
+			staff_lines: 5,
+			line_detail: Vec::new(),
+			staff_tuning: Vec::new(),
+			capo: 0,
+			staff_size: None,
+			show_frets: ShowFrets::default(),
+			print_object: YesNo::Yes,
+		}
+	}
+}
+
+impl StaffConfig {
+	/// Resolves the configuration in effect after the given staff-details elements, in document order, starting from a regular five-line staff.
+	///
+	/// This stands in for a `Part::staff_config(staff_no, measure)` lookup until the crate has a part model: pass the staff-details elements of the staff numbered `staff_no`, from the start of the part up to and including `measure`.
+	pub fn resolve<'a, I: IntoIterator<Item = &'a StaffDetails>>(details: I) -> StaffConfig {
+		let mut config = StaffConfig::default();
+		for staff_details in details {
+			config.apply(staff_details);
+		}
+		config
+	}
+
+	/// Updates the configuration with the values specified by a staff-details element. Line details are reset when the number of staff lines changes, and staff tunings replace earlier tunings of the same line.
+	pub fn apply(&mut self, details: &StaffDetails) {
+		if let Some(staff_type) = details.staff_type {
+			self.staff_type = staff_type;
+		}
+		if let Some(staff_lines) = details.staff_lines {
+			if staff_lines != self.staff_lines {
+				self.line_detail.clear();
+			}
+			self.staff_lines = staff_lines;
+		}
+		for line_detail in &details.line_detail {
+			self.line_detail.retain(|detail| detail.line != line_detail.line);
+			self.line_detail.push(line_detail.clone());
+		}
+		for staff_tuning in &details.staff_tuning {
+			self.staff_tuning.retain(|tuning| tuning.line != staff_tuning.line);
+			self.staff_tuning.push(staff_tuning.clone());
+		}
+		self.staff_tuning.sort_by_key(|tuning| tuning.line);
+		if let Some(capo) = details.capo {
+			self.capo = capo;
+		}
+		if let Some(ref staff_size) = details.staff_size {
+			self.staff_size = Some(staff_size.clone());
+		}
+		if let Some(show_frets) = details.show_frets {
+			self.show_frets = show_frets;
+		}
+		if let Some(print_object) = details.print_object {
+			self.print_object = print_object;
+		}
+	}
+
+	/// Returns the sounding pitch of a string stopped at the given fret. Fret numbers are counted from the capo, so the capo raises every string by its fret number. Returns None if the staff line has no tuning.
+	pub fn fretted_semitone(&self, line: StaffLine, fret: u64) -> Option<Semitones> {
+		let tuning = self.staff_tuning.iter().find(|tuning| tuning.line == line)?;
+		Some(tuning.pitch().semitone_number() + (self.capo + fret) as Semitones)
+	}
+}
+
+/// The part-symbol type indicates how a symbol for a multi-staff part is indicated in the score; brace is the default value. The top-staff and bottom-staff attributes are used when the brace does not extend across the entire part. For example, in a 3-staff organ part, the top-staff will typically be 1 for the right hand, while the bottom-staff will typically be 2 for the left hand. Staff 3 for the pedals is usually outside the brace. By default, the presence of a part-symbol element that does not extend across the entire part also indicates a corresponding change in the common barlines within a part.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PartSymbol {
+	#[serde(rename = "$value")]
+	pub value: GroupSymbolValue,
+	#[serde(rename = "top-staff")]
+	pub top_staff: Option<StaffNumber>,
+	#[serde(rename = "bottom-staff")]
+	pub bottom_staff: Option<StaffNumber>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	pub color: Option<Color>,
+}
+
+impl PartSymbol {
+	/// Returns the range of staves covered by the symbol in a part with the given number of staves.
+	pub fn staves(&self, staff_count: StaffNumber) -> (StaffNumber, StaffNumber) {
+		(self.top_staff.unwrap_or(1), self.bottom_staff.unwrap_or(staff_count))
+	}
+}
+
+/// The harmony-alter type represents the chromatic alteration of the root, numeral, or bass of the current harmony-chord group within the harmony element. In some chord styles, the text of the preceding element may include alteration information. In that case, the print-object attribute of this type can be set to no. The location attribute indicates whether the alteration should appear to the left or the right of the preceding element. Its default value varies by element.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct HarmonyAlter {
+	#[serde(rename = "$value")]
+	pub value: Semitones,
+	#[serde(rename = "print-object")]
+	pub print_object: Option<YesNo>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub location: Option<LeftRight>,
+}
+
+/// The root-step type represents the pitch step of the root of the current chord within the harmony element. The text attribute indicates how the root should appear in a score if not using the element contents.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct RootStep {
+	#[serde(rename = "$value")]
+	pub value: Step,
+	pub text: Option<String>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+}
+
+/// The root type indicates a pitch like C, D, E vs. a scale degree like 1, 2, 3. It is used with chord symbols in popular music. The root element has a root-step and optional root-alter element similar to the step and alter elements, but renamed to distinguish the different musical meanings.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Root {
+	#[serde(rename = "root-step")]
+	pub root_step: RootStep,
+	#[serde(rename = "root-alter")]
+	pub root_alter: Option<HarmonyAlter>,
+}
+
+/// The numeral-root type represents the Roman numeral or Nashville number as a positive integer from 1 to 7. The text attribute indicates how the numeral should appear in the score. A numeral-root value of 5 with a kind of major would have a text attribute of "V" if displayed as a Roman numeral, and "5" if displayed as a Nashville number. If the text attribute is not specified, the display is application-dependent.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct NumeralRoot {
+	#[serde(rename = "$value")]
+	pub value: NumeralValue,
+	pub text: Option<String>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+}
+
+/// The numeral-key type is used when the key for the numeral is different than the key specified by the key signature. The numeral-fifths element specifies the key in the same way as the fifths element. The numeral-mode element specifies the mode similar to the mode element, but with a restricted set of values.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct NumeralKey {
+	#[serde(rename = "numeral-fifths")]
+	pub numeral_fifths: Fifths,
+	#[serde(rename = "numeral-mode")]
+	pub numeral_mode: NumeralMode,
+	#[serde(rename = "print-object")]
+	pub print_object: Option<YesNo>,
+}
+
+/// The numeral type represents the Roman numeral or Nashville number part of a harmony. It requires that the key be specified in the encoding, either with a key or numeral-key element.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Numeral {
+	#[serde(rename = "numeral-root")]
+	pub numeral_root: NumeralRoot,
+	#[serde(rename = "numeral-alter")]
+	pub numeral_alter: Option<HarmonyAlter>,
+	#[serde(rename = "numeral-key")]
+	pub numeral_key: Option<NumeralKey>,
+}
+
+/// Returns the alteration a numeral mode adds to a scale degree on top of the key signature.
+fn numeral_mode_alter(mode: NumeralMode, degree: NumeralValue) -> Semitones {
+	match (mode, degree) {
+		(NumeralMode::HarmonicMinor, 7) | (NumeralMode::MelodicMinor, 6) | (NumeralMode::MelodicMinor, 7) => 1.0,
+		_ => 0.0,
+	}
+}
+
+/// Returns the tonic of a key given by its fifths and numeral mode.
+fn numeral_tonic(fifths: Fifths, mode: NumeralMode) -> Step {
+	let mode = if mode == NumeralMode::Major { Mode::Major } else { Mode::Minor };
+	Key::from_fifths(fifths, None).tonic(&mode).unwrap().0
+}
+
+impl Numeral {
+	/// Returns the key the numeral is relative to: the numeral-key element if present, otherwise the given key signature. Modes other than minor and aeolian are read as major.
+	pub fn key(&self, key: &Key) -> (Fifths, NumeralMode) {
+		match &self.numeral_key {
+			Some(numeral_key) => (numeral_key.numeral_fifths, numeral_key.numeral_mode),
+			None => match key.mode {
+				Some(Mode::Minor) | Some(Mode::Aeolian) => (key.fifths, NumeralMode::Minor),
+				_ => (key.fifths, NumeralMode::Major),
+			},
+		}
+	}
+
+	/// Returns the absolute root of the numeral as a step and chromatic alteration, in the key given by the numeral-key element or else the given key signature. Returns None for numeral-root values outside 1 to 7.
+	pub fn root(&self, key: &Key) -> Option<(Step, Semitones)> {
+		let degree = self.numeral_root.value;
+		if !(1..=7).contains(&degree) {
+			return None;
+		}
+		let (fifths, mode) = self.key(key);
+		let step = Step::from_index(numeral_tonic(fifths, mode).index() + i64::from(degree) - 1);
+		let alter = Key::from_fifths(fifths, None).accidental_for(step)
+			+ numeral_mode_alter(mode, degree)
+			+ self.numeral_alter.as_ref().map_or(0.0, |alter| alter.value);
+		Some((step, alter))
+	}
+
+	/// Returns the numeral-root value and numeral-alter value for an absolute root in the given key. This is the inverse of root.
+	pub fn degree_of(step: Step, alter: Semitones, fifths: Fifths, mode: NumeralMode) -> (NumeralValue, Semitones) {
+		let degree = (step.index() - numeral_tonic(fifths, mode).index()).rem_euclid(7) as NumeralValue + 1;
+		let alter = alter - Key::from_fifths(fifths, None).accidental_for(step) - numeral_mode_alter(mode, degree);
+		(degree, alter)
+	}
+}
+
+/// Kind indicates the type of chord. Degree elements can then add, subtract, or alter from these starting points.
+///
+/// The attributes are used to indicate the formatting of the symbol. Since the kind element is the constant in all the harmony-chord groups that can make up a polychord, many formatting attributes are here.
+///
+/// The use-symbols attribute is yes if the kind should be represented when possible with harmony symbols rather than letters and numbers. These symbols include major triangles, minus signs, plus signs, and circles. The text attribute describes how the kind should be spelled in a score. If use-symbols is yes, the value of the text attribute follows the symbol. The stack-degrees attribute is yes if the degree elements should be stacked above each other. The parentheses-degrees attribute is yes if all the degrees should be in parentheses. The bracket-degrees attribute is yes if all the degrees should be in a bracket. If not specified, these values are implementation-specific. The alignment attributes are for the entire harmony-chord group of which this kind element is a part.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Kind {
+	#[serde(rename = "$value")]
+	pub value: KindValue,
+	#[serde(rename = "use-symbols")]
+	pub use_symbols: Option<YesNo>,
+	pub text: Option<String>,
+	#[serde(rename = "stack-degrees")]
+	pub stack_degrees: Option<YesNo>,
+	#[serde(rename = "parentheses-degrees")]
+	pub parentheses_degrees: Option<YesNo>,
+	#[serde(rename = "bracket-degrees")]
+	pub bracket_degrees: Option<YesNo>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+}
+
+/// The inversion type represents harmony inversions. The value is a number indicating which inversion is used: 0 for root position, 1 for first inversion, etc.  The text attribute indicates how the inversion should be displayed in a score.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Inversion {
+	#[serde(rename = "$value")]
+	pub value: u64,
+	pub text: Option<String>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+}
+
+/// The bass-step type represents the pitch step of the bass of the current chord within the harmony element. The text attribute indicates how the bass should appear in a score if not using the element contents.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct BassStep {
+	#[serde(rename = "$value")]
+	pub value: Step,
+	pub text: Option<String>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+}
+
+/// The bass type is used to indicate a bass note in popular music chord symbols, e.g. G/C. It is generally not used in functional harmony, as inversion is generally not used in pop chord symbols. As with root, it is divided into step and alter elements, similar to pitches. The arrangement attribute specifies where the bass is displayed relative to what precedes it.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Bass {
+	#[serde(rename = "bass-step")]
+	pub bass_step: BassStep,
+	#[serde(rename = "bass-alter")]
+	pub bass_alter: Option<HarmonyAlter>,
+	pub arrangement: Option<HarmonyArrangement>,
+}
+
+/// The content of the degree-value type is a number indicating the degree of the chord (1 for the root, 3 for third, etc). The text attribute specifies how the value of the degree should be displayed. The symbol attribute indicates that a symbol should be used in specifying the degree. If the symbol attribute is present, the value of the text attribute follows the symbol.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct DegreeValue {
+	#[serde(rename = "$value")]
+	pub value: u64,
+	pub symbol: Option<DegreeSymbolValue>,
+	pub text: Option<String>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+}
+
+/// The degree-alter type represents the chromatic alteration for the current degree. If the degree-type value is alter or subtract, the degree-alter value is relative to the degree already in the chord based on its kind element. If the degree-type value is add, the degree-alter is relative to a dominant chord (major and perfect intervals except for a minor seventh). The plus-minus attribute is used to indicate if plus and minus symbols should be used instead of sharp and flat symbols to display the degree alteration. It is no if not present.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct DegreeAlter {
+	#[serde(rename = "$value")]
+	pub value: Semitones,
+	#[serde(rename = "plus-minus")]
+	pub plus_minus: Option<YesNo>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+}
+
+/// The degree-type type indicates if this degree is an addition, alteration, or subtraction relative to the kind of the current chord. The value of the degree-type element affects the interpretation of the value of the degree-alter element. The text attribute specifies how the type of the degree should be displayed.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct DegreeType {
+	#[serde(rename = "$value")]
+	pub value: DegreeTypeValue,
+	pub text: Option<String>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+}
+
+/// The degree type is used to add, alter, or subtract individual notes in the chord. The print-object attribute can be used to keep the degree from printing separately when it has already taken into account in the text attribute of the kind element. The degree-value and degree-type text attributes specify how the value and type of the degree should be displayed.
+///
+/// A harmony of kind "other" can be spelled explicitly by using a series of degree elements together with a root.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Degree {
+	#[serde(rename = "degree-value")]
+	pub degree_value: DegreeValue,
+	#[serde(rename = "degree-alter")]
+	pub degree_alter: DegreeAlter,
+	#[serde(rename = "degree-type")]
+	pub degree_type: DegreeType,
+	#[serde(rename = "print-object")]
+	pub print_object: Option<YesNo>,
+}
+
+/// An offset is represented in terms of divisions, and indicates where the figured bass, harmony, or direction occurs relative to the cursor location. The optional sound attribute indicates whether the offset affects playback. It is no by default.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Offset {
+	#[serde(rename = "$value")]
+	pub value: Divisions,
+	pub sound: Option<YesNo>,
+}
+
+/// The harmony type represents harmony analysis, including chord symbols in popular music as well as functional harmony analysis in classical music.
+///
+/// If there are alternate harmonies possible, this can be specified using multiple harmony elements differentiated by type. Explicit harmonies have all note present in the music; implied have some notes missing but implied; alternate represents alternate analyses.
+///
+/// The print-object attribute controls whether or not anything is printed due to the harmony element. The print-frame attribute controls printing of a frame or fretboard diagram. The print-style attribute group sets the default for the harmony, but individual elements can override this with their own print-style values. The arrangement attribute specifies how multiple harmony-chord groups are arranged relative to each other.
+///
+/// Only harmonies with a single harmony-chord group are modelled so far.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Harmony {
+	pub root: Option<Root>,
+	pub numeral: Option<Numeral>,
+	pub kind: Kind,
+	pub inversion: Option<Inversion>,
+	pub bass: Option<Bass>,
+	#[serde(default)]
+	pub degree: Vec<Degree>,
+	pub offset: Option<Offset>,
+	pub staff: Option<StaffNumber>,
+	#[serde(rename = "type")]
+	pub harmony_type: Option<HarmonyType>,
+	#[serde(rename = "print-object")]
+	pub print_object: Option<YesNo>,
+	#[serde(rename = "print-frame")]
+	pub print_frame: Option<YesNo>,
+	pub arrangement: Option<HarmonyArrangement>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+	#[serde(rename = "system")]
+	pub system_relation: Option<SystemRelation>,
+	pub id: Option<String>,
+}
+
+/// Returns the chord tones of a kind as pairs of degree and semitones above the root.
+fn kind_degrees(kind: KindValue) -> Vec<(u64, Semitones)> {
+	let tones: &[(u64, Semitones)] = match kind {
+		KindValue::Major | KindValue::Neapolitan => &[(3, 4.0), (5, 7.0)],
+		KindValue::Minor => &[(3, 3.0), (5, 7.0)],
+		KindValue::Augmented => &[(3, 4.0), (5, 8.0)],
+		KindValue::Diminished => &[(3, 3.0), (5, 6.0)],
+		KindValue::Dominant => &[(3, 4.0), (5, 7.0), (7, 10.0)],
+		KindValue::MajorSeventh => &[(3, 4.0), (5, 7.0), (7, 11.0)],
+		KindValue::MinorSeventh => &[(3, 3.0), (5, 7.0), (7, 10.0)],
+		KindValue::DiminishedSeventh => &[(3, 3.0), (5, 6.0), (7, 9.0)],
+		KindValue::AugmentedSeventh => &[(3, 4.0), (5, 8.0), (7, 10.0)],
+		KindValue::HalfDiminished => &[(3, 3.0), (5, 6.0), (7, 10.0)],
+		KindValue::MajorMinor => &[(3, 3.0), (5, 7.0), (7, 11.0)],
+		KindValue::MajorSixth => &[(3, 4.0), (5, 7.0), (6, 9.0)],
+		KindValue::MinorSixth => &[(3, 3.0), (5, 7.0), (6, 9.0)],
+		KindValue::DominantNinth => &[(3, 4.0), (5, 7.0), (7, 10.0), (9, 14.0)],
+		KindValue::MajorNinth => &[(3, 4.0), (5, 7.0), (7, 11.0), (9, 14.0)],
+		KindValue::MinorNinth => &[(3, 3.0), (5, 7.0), (7, 10.0), (9, 14.0)],
+		KindValue::Dominant11th => &[(3, 4.0), (5, 7.0), (7, 10.0), (9, 14.0), (11, 17.0)],
+		KindValue::Major11th => &[(3, 4.0), (5, 7.0), (7, 11.0), (9, 14.0), (11, 17.0)],
+		KindValue::Minor11th => &[(3, 3.0), (5, 7.0), (7, 10.0), (9, 14.0), (11, 17.0)],
+		KindValue::Dominant13th => &[(3, 4.0), (5, 7.0), (7, 10.0), (9, 14.0), (11, 17.0), (13, 21.0)],
+		KindValue::Major13th => &[(3, 4.0), (5, 7.0), (7, 11.0), (9, 14.0), (11, 17.0), (13, 21.0)],
+		KindValue::Minor13th => &[(3, 3.0), (5, 7.0), (7, 10.0), (9, 14.0), (11, 17.0), (13, 21.0)],
+		KindValue::SuspendedSecond => &[(2, 2.0), (5, 7.0)],
+		KindValue::SuspendedFourth => &[(4, 5.0), (5, 7.0)],
+		KindValue::Italian => &[(3, 4.0), (6, 10.0)],
+		KindValue::French => &[(3, 4.0), (4, 6.0), (6, 10.0)],
+		KindValue::German => &[(3, 4.0), (5, 7.0), (6, 10.0)],
+		KindValue::Power => &[(5, 7.0)],
+		KindValue::Tristan => &[(4, 6.0), (6, 10.0), (9, 15.0)],
+		KindValue::Pedal | KindValue::Other => &[],
+		KindValue::None => return Vec::new(),
+	};
+	let mut degrees = vec![(1, 0.0)];
+	degrees.extend_from_slice(tones);
+	degrees
+}
+
+/// Returns the semitones above the root of an added degree, relative to a dominant chord: major and perfect intervals except for a minor seventh.
+fn dominant_degree(degree: u64) -> Semitones {
+	let index = degree.saturating_sub(1);
+	let semitones = if index % 7 == 6 { 10 } else { Step::from_index(index as i64).semitone() };
+	(semitones + 12 * (index / 7) as i64) as Semitones
+}
+
+/// Returns the pitch the given number of semitones above another, spelled as the given diatonic degree (1 for a unison, 3 for a third, and so on).
+fn interval_above(pitch: &Pitch, degree: u64, semitones: Semitones) -> Pitch {
+	let diatonic = pitch.diatonic_number() + degree as i64 - 1;
+	let step = Step::from_index(diatonic);
+	let octave = diatonic.div_euclid(7);
+	let natural = (octave * 12 + step.semitone()) as Semitones;
+	let alter = pitch.semitone_number() + semitones - natural;
+	Pitch {
+		step,
+		alter: if alter == 0.0 { None } else { Some(alter) },
+		octave: octave as Octave,
+	}
+}
+
+impl Harmony {
+	/// Returns the root of the chord as a step and chromatic alteration. A Roman numeral or Nashville number is resolved against the given key signature unless it carries its own numeral-key.
+	pub fn root_pitch(&self, key: &Key) -> Option<(Step, Semitones)> {
+		match (&self.root, &self.numeral) {
+			(Some(root), _) => Some((root.root_step.value, root.root_alter.as_ref().map_or(0.0, |alter| alter.value))),
+			(None, Some(numeral)) => numeral.root(key),
+			(None, None) => None,
+		}
+	}
+
+	/// Returns the pitches of the chord, with the root in the given octave, spelled from the root step by degree. The root is found as for `root_pitch`, so Roman numerals and Nashville numbers are resolved against the given key signature. Degree elements are applied to the tones of the kind, an inversion raises the lowest chord tones by an octave, and a bass note is placed in the octave below the root. Returns an empty list for harmonies without a root or of kind none.
+	pub fn pitches(&self, key: &Key, octave_hint: Octave) -> Vec<Pitch> {
+		let root = match self.root_pitch(key) {
+			Some((step, alter)) => Pitch {
+				step,
+				alter: if alter == 0.0 { None } else { Some(alter) },
+				octave: octave_hint,
+			},
+			None => return Vec::new(),
+		};
+		let mut degrees = kind_degrees(self.kind.value);
+		if degrees.is_empty() {
+			return Vec::new();
+		}
+		for degree in &self.degree {
+			let value = degree.degree_value.value;
+			let alter = degree.degree_alter.value;
+			let existing = degrees.iter().position(|&(d, _)| d == value);
+			match (degree.degree_type.value, existing) {
+				(DegreeTypeValue::Alter, Some(index)) => degrees[index].1 += alter,
+				(DegreeTypeValue::Subtract, Some(index)) => {
+					degrees.remove(index);
+				}
+				(DegreeTypeValue::Add, _) | (DegreeTypeValue::Alter, None) => degrees.push((value, dominant_degree(value) + alter)),
+				(DegreeTypeValue::Subtract, None) => {}
+			}
+		}
+		let mut pitches: Vec<Pitch> = degrees.iter().map(|&(degree, semitones)| interval_above(&root, degree, semitones)).collect();
+		pitches.sort_by(|a, b| a.semitone_number().partial_cmp(&b.semitone_number()).unwrap_or(std::cmp::Ordering::Equal));
+		if let Some(inversion) = &self.inversion {
+			let count = (inversion.value as usize).min(pitches.len());
+			for pitch in pitches.iter_mut().take(count) {
+				pitch.octave += 1;
+			}
+			pitches.rotate_left(count);
+		}
+		if let Some(bass) = &self.bass {
+			pitches.insert(0, Pitch {
+				step: bass.bass_step.value,
+				alter: bass.bass_alter.as_ref().map(|alter| alter.value),
+				octave: octave_hint.saturating_sub(1),
+			});
+		}
+		pitches
+	}
+}
+
+/// The player type allows for multiple players per score-part for use in listening applications. One player may play multiple instruments, while a single instrument may include multiple players in divisi sections.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Player {
+	#[serde(rename = "player-name")]
+	pub player_name: String,
+	pub id: String,
+}
+
+/// The virtual-instrument element defines a specific virtual instrument used for an instrument sound.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct VirtualInstrument {
+	#[serde(rename = "virtual-library")]
+	pub virtual_library: Option<String>,
+	#[serde(rename = "virtual-name")]
+	pub virtual_name: Option<String>,
+}
+
+/// The instrument-change element type represents a change to the virtual instrument sound for a given score-instrument. The id attribute refers to the score-instrument affected by the change. All instrument-change child elements can also be initially specified within the score-instrument element.
+///
+/// The solo element is present if performance is intended by a solo instrument. The ensemble element is present if performance is intended by an ensemble such as an orchestral section. The text of the ensemble element contains the size of the section, or is empty if the ensemble size is not specified.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct InstrumentChange {
+	#[serde(rename = "instrument-sound")]
+	pub instrument_sound: Option<String>,
+	pub solo: Option<Empty>,
+	pub ensemble: Option<PositiveIntegerOrEmpty>,
+	#[serde(rename = "virtual-instrument")]
+	pub virtual_instrument: Option<VirtualInstrument>,
+	pub id: String,
+}
+
+/// Multiple part-link elements can link a condensed part within a score file to multiple MusicXML parts files. For example, a "Clarinet 1 and 2" part in a score file could link to separate "Clarinet 1" and "Clarinet 2" part files. The instrument-link type distinguish which of the score-instruments within a score-part are in which part file. The instrument-link id attribute refers to a score-instrument id attribute.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct InstrumentLink {
+	pub id: String,
+}
+
+/// By default, an assessment application should assess all notes without a cue child element, and not assess any note with a cue child element. The assess type allows this default assessment to be overridden for individual notes. The optional player and time-only attributes restrict the type to apply to a single player or set of times through a repeated section, respectively. If missing, the type applies to all players or all times through the repeated section, respectively. The player attribute references the id attribute of a player element defined within the matching score-part.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Assess {
+	#[serde(rename = "type")]
+	pub assess_type: YesNo,
+	pub player: Option<String>,
+	#[serde(rename = "time-only")]
+	pub time_only: Option<TimeOnly>,
+}
+
+/// The wait type specifies a point where the accompaniment should wait for a performer event before continuing. This typically happens at the start of new sections or after a held note or indeterminate music. These waiting points cannot always be inferred reliably from the contents of the displayed score. The optional player and time-only attributes restrict the type to apply to a single player or set of times through a repeated section, respectively.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Wait {
+	pub player: Option<String>,
+	#[serde(rename = "time-only")]
+	pub time_only: Option<TimeOnly>,
+}
+
+/// The other-listening type represents other types of listening control and interaction.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct OtherListening {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	#[serde(rename = "type")]
+	pub listening_type: String,
+	pub player: Option<String>,
+	#[serde(rename = "time-only")]
+	pub time_only: Option<TimeOnly>,
+}
+
+/// One interaction within a listen element.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ListenContent {
+	Assess(Assess),
+	Wait(Wait),
+	OtherListen(OtherListening),
+}
+
+/// The listen and listening types, new in Version 4.0, specify different ways that a score following or machine listening application can interact with a performer. The listen type handles interactions that are specific to a note. If multiple child elements of the same type are present, they should have distinct player and/or time-only attributes.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Listen {
+	#[serde(rename = "$value")]
+	pub content: Vec<ListenContent>,
+}
+
+/// The sync type specifies the style that a score following application should use the synchronize an accompaniment with a performer. If this type is not included in a score, default synchronization depends on the application. The optional latency attribute specifies a time in milliseconds that the listening application should expect from the performer. The optional player and time-only attributes restrict the element to apply to a single player or set of times through a repeated section, respectively.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Sync {
+	#[serde(rename = "type")]
+	pub sync_type: SyncType,
+	pub latency: Option<u64>,
+	pub player: Option<String>,
+	#[serde(rename = "time-only")]
+	pub time_only: Option<TimeOnly>,
+}
+
+/// One state change within a listening element.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ListeningContent {
+	Sync(Sync),
+	OtherListening(OtherListening),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum ListeningElement {
+	Sync(Sync),
+	OtherListening(OtherListening),
+	Offset(Offset),
+}
+
+#[derive(Deserialize)]
+struct RawListening {
+	#[serde(rename = "$value")]
+	elements: Vec<ListeningElement>,
+}
+
+/// The listen and listening types, new in Version 4.0, specify different ways that a score following or machine listening application can interact with a performer. The listening type handles interactions that change the state of the listening application from the specified point in the performance onward. If multiple child elements of the same type are present, they should have distinct player and/or time-only attributes.
+///
+/// The offset element is used to indicate that the listening change takes place offset from the current score position. If the listening element is a child of a direction element, the listening offset element overrides the direction offset element if both elements are present. Note that the offset reflects the intended musical position for the change in state. It should not be used to compensate for latency issues in particular hardware configurations.
+#[derive(Debug, PartialEq)]
+pub struct Listening {
+	pub content: Vec<ListeningContent>,
+	pub offset: Option<Offset>,
+}
+
+impl<'de> Deserialize<'de> for Listening {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let raw = RawListening::deserialize(deserializer)?;
+		let mut listening = Listening {
+			content: Vec::new(),
+			offset: None,
+		};
+		for element in raw.elements {
+			match element {
+				ListeningElement::Sync(sync) => listening.content.push(ListeningContent::Sync(sync)),
+				ListeningElement::OtherListening(other) => listening.content.push(ListeningContent::OtherListening(other)),
+				ListeningElement::Offset(offset) => listening.offset = Some(offset),
+			}
+		}
+		Ok(listening)
+	}
+}
+
+/// The other-play element represents other types of playback. The required type attribute indicates the type of playback to which the element content applies.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct OtherPlay {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	#[serde(rename = "type")]
+	pub play_type: String,
+}
+
+/// One playback technique within a play element. The ipa element represents International Phonetic Alphabet (IPA) sounds for vocal music. String content is limited to IPA 2015 symbols represented in Unicode 13.0.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum PlayContent {
+	Ipa(String),
+	Mute(Mute),
+	SemiPitched(SemiPitched),
+	OtherPlay(OtherPlay),
+}
+
+/// The play type specifies playback techniques to be used in conjunction with the instrument-sound element. When used as part of a sound element, it applies to all notes going forward in score order. In multi-instrument parts, the affected instrument should be specified using the id attribute. When used as part of a note element, it applies to the current note only.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Play {
+	#[serde(rename = "$value", default)]
+	pub content: Vec<PlayContent>,
+	pub id: Option<String>,
+}
+
+/// One mark within a dynamics element. The letter dynamics are named after the letters they display; other-dynamics allows other dynamic marks that are not covered by the letter dynamics.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DynamicsValue {
+	P,
+	Pp,
+	Ppp,
+	Pppp,
+	Ppppp,
+	Pppppp,
+	F,
+	Ff,
+	Fff,
+	Ffff,
+	Fffff,
+	Ffffff,
+	Mp,
+	Mf,
+	Sf,
+	Sfp,
+	Sfpp,
+	Fp,
+	Rf,
+	Rfz,
+	Sfz,
+	Sffz,
+	Fz,
+	N,
+	Pf,
+	Sfzp,
+	#[serde(rename = "other-dynamics")]
+	OtherDynamics(OtherText),
+}
+
+impl DynamicsValue {
+	/// Returns a conventional MIDI note-on velocity for the mark, or None for other-dynamics. Marks that combine an accent with a following level, such as sfp or fp, use the velocity of the accent.
+	pub fn velocity(&self) -> Option<u8> {
+		let velocity = match self {
+			DynamicsValue::N => 0,
+			DynamicsValue::Pppppp => 1,
+			DynamicsValue::Ppppp => 5,
+			DynamicsValue::Pppp => 10,
+			DynamicsValue::Ppp => 16,
+			DynamicsValue::Pp => 33,
+			DynamicsValue::P | DynamicsValue::Pf => 49,
+			DynamicsValue::Mp => 64,
+			DynamicsValue::Mf => 80,
+			DynamicsValue::F | DynamicsValue::Fp => 96,
+			DynamicsValue::Ff
+			| DynamicsValue::Sf
+			| DynamicsValue::Sfp
+			| DynamicsValue::Sfpp
+			| DynamicsValue::Rf
+			| DynamicsValue::Rfz
+			| DynamicsValue::Sfz
+			| DynamicsValue::Fz
+			| DynamicsValue::Sfzp => 112,
+			DynamicsValue::Fff | DynamicsValue::Sffz => 126,
+			DynamicsValue::Ffff | DynamicsValue::Fffff | DynamicsValue::Ffffff => 127,
+			DynamicsValue::OtherDynamics(_) => return None,
+		};
+		Some(velocity)
+	}
+}
+
+/// Dynamics can be associated either with a note or a general musical direction. To avoid inconsistencies between and amongst the letter abbreviations for dynamics (what is sf vs. sfz, standing alone or with a trailing dynamic that is not always piano), we use the actual letters as the names of these dynamic elements. The other-dynamics element allows other dynamic marks that are not covered here. Dynamics elements may also be combined to create marks not covered by a single element, such as sfmp.
+///
+/// These letter dynamic symbols are separated from crescendo, decrescendo, and wedge indications. Dynamic representation is inconsistent in scores. Many things are assumed by the composer and left out, such as returns to original dynamics. The MusicXML format captures what is in the score, but does not try to be optimal for analysis or synthesis of dynamics.
+///
+/// The placement attribute is used when the dynamics are associated with a note. It is ignored when the dynamics are associated with a direction. In that case the direction element's placement attribute is used instead.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Dynamics {
+	#[serde(rename = "$value", default)]
+	pub marks: Vec<DynamicsValue>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+	pub placement: Option<AboveBelow>,
+	pub underline: Option<NumberOfLines>,
+	pub overline: Option<NumberOfLines>,
+	#[serde(rename = "line-through")]
+	pub line_through: Option<NumberOfLines>,
+	pub enclosure: Option<EnclosureShape>,
+	pub id: Option<String>,
+}
+
+impl Dynamics {
+	/// Returns the MIDI velocity of the first mark that has one, so that a combined mark such as sfmp is played with the velocity of its accent.
+	pub fn velocity(&self) -> Option<u8> {
+		self.marks.iter().find_map(DynamicsValue::velocity)
+	}
+}
+
+/// The per-minute type can be a number, or a text description including numbers. If a font is specified, it overrides the font specified for the overall metronome element. This allows separate specification of a music font for the beat-unit and a text font for the numeric value, in cases where a single metronome font is not used.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct PerMinute {
+	#[serde(rename = "$value")]
+	pub value: String,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+}
+
+impl PerMinute {
+	/// Returns the first number in the text, such as 120 in "c. 120" or "120-132".
+	pub fn number(&self) -> Option<f64> {
+		let start = self.value.find(|c: char| c.is_ascii_digit())?;
+		let number = &self.value[start..];
+		let end = number.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(number.len());
+		number[..end].trim_end_matches('.').parse().ok()
+	}
+}
+
+/// The beat-unit element indicates the graphical note type to use in a metronome mark, and the beat-unit-dot element is used to specify any augmentation dots for a metronome mark note. The beat-unit-tied type indicates a beat-unit within a metronome mark that is tied to the preceding beat-unit. This allows two or more tied notes to be associated with a per-minute value in a metronome mark, whereas the metronome-tied element is restricted to metric relationship marks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BeatUnitGroup {
+	pub beat_unit: NoteTypeValue,
+	pub beat_unit_dots: u32,
+	pub beat_unit_tied: Vec<(NoteTypeValue, u32)>,
+}
+
+impl BeatUnitGroup {
+	/// Returns the total duration of the beat unit and any tied beat units, or None if there are too many dots for it to fit.
+	pub fn duration(&self) -> Option<Duration> {
+		self.beat_unit_tied
+			.iter()
+			.try_fold(Duration::from_note_type(self.beat_unit, self.beat_unit_dots)?, |total, &(note, dots)| {
+				total.checked_add(Duration::from_note_type(note, dots)?)
+			})
+	}
+}
+
+/// The metronome-beam type works like the beam type in defining metric relationships, but does not include all the attributes available in the beam type.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct MetronomeBeam {
+	#[serde(rename = "$value")]
+	pub value: BeamValue,
+	pub number: Option<BeamLevel>,
+}
+
+/// The metronome-tied indicates the presence of a tie within a metric relationship mark. As with the tied element, both the start and stop of the tie should be specified, in this case within separate metronome-note elements.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct MetronomeTied {
+	#[serde(rename = "type")]
+	pub tied_type: StartStop,
+}
+
+/// The metronome-tuplet type uses the same element structure as the time-modification element along with some attributes from the tuplet element.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct MetronomeTuplet {
+	#[serde(rename = "actual-notes")]
+	pub actual_notes: u64,
+	#[serde(rename = "normal-notes")]
+	pub normal_notes: u64,
+	#[serde(rename = "normal-type")]
+	pub normal_type: Option<NoteTypeValue>,
+	#[serde(rename = "normal-dot", default)]
+	pub normal_dot: Vec<Empty>,
+	#[serde(rename = "type")]
+	pub tuplet_type: StartStop,
+	pub bracket: Option<YesNo>,
+	#[serde(rename = "show-number")]
+	pub show_number: Option<ShowTuplet>,
+}
+
+/// The metronome-note type defines the appearance of a note within a metric relationship mark.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct MetronomeNote {
+	#[serde(rename = "metronome-type")]
+	pub metronome_type: NoteTypeValue,
+	#[serde(rename = "metronome-dot", default)]
+	pub metronome_dot: Vec<Empty>,
+	#[serde(rename = "metronome-beam", default)]
+	pub metronome_beam: Vec<MetronomeBeam>,
+	#[serde(rename = "metronome-tied")]
+	pub metronome_tied: Option<MetronomeTied>,
+	#[serde(rename = "metronome-tuplet")]
+	pub metronome_tuplet: Option<MetronomeTuplet>,
+}
+
+/// What a beat unit is set equal to in a metronome mark: either a tempo in beats per minute, or another beat unit in a metric modulation.
+#[derive(Debug, PartialEq)]
+pub enum MetronomeTempo {
+	PerMinute(PerMinute),
+	BeatUnit(BeatUnitGroup),
+}
+
+/// The content of a metronome element. The first form is the usual beat-unit with a per-minute value or an equation between two beat units. The second form uses metronome-note elements to show a metric relationship mark, such as a swing or modulation mark; the optional metronome-arrows element indicates that the metric relationship should be displayed with arrows, and the metronome-relation element (currently only "equals") joins the two sides.
+#[derive(Debug, PartialEq)]
+pub enum MetronomeContent {
+	BeatUnit {
+		beat_unit: BeatUnitGroup,
+		tempo: MetronomeTempo,
+	},
+	Notes {
+		metronome_arrows: bool,
+		notes: Vec<MetronomeNote>,
+		metronome_relation: Option<String>,
+		related_notes: Vec<MetronomeNote>,
+	},
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum MetronomeElement {
+	BeatUnit(NoteTypeValue),
+	BeatUnitDot(Empty),
+	BeatUnitTied(RawBeatUnitTied),
+	PerMinute(PerMinute),
+	MetronomeArrows(Empty),
+	MetronomeNote(MetronomeNote),
+	MetronomeRelation(String),
+}
+
+#[derive(Deserialize)]
+struct RawBeatUnitTied {
+	#[serde(rename = "beat-unit")]
+	beat_unit: NoteTypeValue,
+	#[serde(rename = "beat-unit-dot", default)]
+	beat_unit_dot: Vec<Empty>,
+}
+
+/// Reads the dots and tied beat units that follow a beat-unit element.
+fn beat_unit_group(beat_unit: NoteTypeValue, elements: &mut Peekable<vec::IntoIter<MetronomeElement>>) -> BeatUnitGroup {
+	let mut group = BeatUnitGroup {
+		beat_unit,
+		beat_unit_dots: 0,
+		beat_unit_tied: Vec::new(),
+	};
+	while let Some(MetronomeElement::BeatUnitDot(_)) = elements.peek() {
+		elements.next();
+		group.beat_unit_dots += 1;
+	}
+	while let Some(MetronomeElement::BeatUnitTied(_)) = elements.peek() {
+		if let Some(MetronomeElement::BeatUnitTied(tied)) = elements.next() {
+			group.beat_unit_tied.push((tied.beat_unit, tied.beat_unit_dot.len() as u32));
+		}
+	}
+	group
+}
+
+impl<'de> Deserialize<'de> for MetronomeContent {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let mut elements = Vec::<MetronomeElement>::deserialize(deserializer)?.into_iter().peekable();
+		let first = match elements.next() {
+			Some(MetronomeElement::BeatUnit(beat_unit)) => {
+				let beat_unit = beat_unit_group(beat_unit, &mut elements);
+				let tempo = match elements.next() {
+					Some(MetronomeElement::PerMinute(per_minute)) => MetronomeTempo::PerMinute(per_minute),
+					Some(MetronomeElement::BeatUnit(other)) => MetronomeTempo::BeatUnit(beat_unit_group(other, &mut elements)),
+					_ => return Err(de::Error::custom("expected per-minute or beat-unit")),
+				};
+				return Ok(MetronomeContent::BeatUnit { beat_unit, tempo });
+			}
+			first => first,
+		};
+		let mut metronome_arrows = false;
+		let mut notes = Vec::new();
+		let mut metronome_relation = None;
+		let mut related_notes = Vec::new();
+		for element in first.into_iter().chain(elements) {
+			match element {
+				MetronomeElement::MetronomeArrows(_) => metronome_arrows = true,
+				MetronomeElement::MetronomeNote(note) if metronome_relation.is_none() => notes.push(note),
+				MetronomeElement::MetronomeNote(note) => related_notes.push(note),
+				MetronomeElement::MetronomeRelation(relation) => metronome_relation = Some(relation),
+				_ => return Err(de::Error::custom("unexpected element in metric relationship mark")),
+			}
+		}
+		if notes.is_empty() {
+			return Err(de::Error::custom("metronome element without beat-unit or metronome-note"));
+		}
+		Ok(MetronomeContent::Notes {
+			metronome_arrows,
+			notes,
+			metronome_relation,
+			related_notes,
+		})
+	}
+}
+
+/// The metronome type represents metronome marks and other metric relationships. The beat-unit group and per-minute element specify regular metronome marks. The metronome-note and metronome-relation elements allow for the specification of metric modulations and other metric relationships, such as swing tempo marks where two eighths are equated to a quarter note / eighth note triplet. Tied notes can be represented in both types of metronome marks by using the beat-unit-tied and metronome-tied elements. The parentheses attribute indicates whether or not to put the metronome mark in parentheses; its value is no if not specified. The print-object attribute is set to no in cases where the metronome element represents a relationship or range that is not displayed in the music notation.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Metronome {
+	#[serde(rename = "$value")]
+	pub content: MetronomeContent,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+	#[serde(rename = "print-object")]
+	pub print_object: Option<YesNo>,
+	pub justify: Option<LeftCenterRight>,
+	pub parentheses: Option<YesNo>,
+	pub id: Option<String>,
+}
+
+impl Metronome {
+	/// Returns the tempo in beats per minute counting in the given note type, so that `bpm_for(NoteTypeValue::Quarter)` gives quarter notes per minute. Returns None for metric relationship marks, for per-minute text without a number and for beat units too long to represent.
+	pub fn bpm_for(&self, note: NoteTypeValue) -> Option<f64> {
+		match &self.content {
+			MetronomeContent::BeatUnit {
+				beat_unit,
+				tempo: MetronomeTempo::PerMinute(per_minute),
+			} => Some(per_minute.number()? * beat_unit.duration()?.as_f64() / Duration::from_note_type(note, 0)?.as_f64()),
+			_ => None,
+		}
+	}
+}
+
+/// The pedal type represents piano pedal marks, including damper and sostenuto pedal marks. The number attribute is used to distinguish multiple pedals within a part. The line attribute is yes if pedal lines are used. The sign attribute is yes if Ped, Sost, and * signs are used. For compatibility with older versions, the sign attribute is yes by default if the line attribute is no, and is no by default if the line attribute is yes. If the sign attribute is set to yes and the type is start or sostenuto, the abbreviated attribute is yes if the short P and S signs are used, and no if the full Ped and Sost signs are used. It is no by default. Otherwise the abbreviated attribute is ignored. The alignment attributes are ignored if the sign attribute is no.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Pedal {
+	#[serde(rename = "type")]
+	pub pedal_type: PedalType,
+	pub number: Option<NumberLevel>,
+	pub line: Option<YesNo>,
+	pub sign: Option<YesNo>,
+	pub abbreviated: Option<YesNo>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+	pub id: Option<String>,
+}
+
+/// A pedal held between two positions, as resolved from a sequence of pedal marks. The stop position is None if the pedal is still down at the end of the sequence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PedalSpan<P> {
+	pub number: NumberLevel,
+	pub sostenuto: bool,
+	pub start: P,
+	pub stop: Option<P>,
+}
+
+impl Pedal {
+	/// Returns whether pedal lines are used, which is no if not specified.
+	pub fn line(&self) -> YesNo {
+		self.line.unwrap_or(YesNo::No)
+	}
+
+	/// Returns whether Ped, Sost, and * signs are used. If not specified, this is yes when the line attribute is no and no when it is yes.
+	pub fn sign(&self) -> YesNo {
+		match (self.sign, self.line()) {
+			(Some(sign), _) => sign,
+			(None, YesNo::Yes) => YesNo::No,
+			(None, YesNo::No) => YesNo::Yes,
+		}
+	}
+
+	/// Resolves a sequence of pedal marks in score order into the spans during which each pedal is held. Positions are supplied by the caller, such as a measure number and beat or an offset in divisions. A change mark ends the current span and starts a new one at the same position, and continue marks do not affect the spans. Discontinue and resume marks end and start spans like stop and start, since they only differ in how the pedal line is drawn. Pedals are told apart by their number attribute.
+	pub fn spans<'a, P, I>(marks: I) -> Vec<PedalSpan<P>>
+	where
+		P: Clone,
+		I: IntoIterator<Item = (P, &'a Pedal)>,
+	{
+		let mut spans: Vec<PedalSpan<P>> = Vec::new();
+		let mut open: Vec<(NumberLevel, usize)> = Vec::new();
+		for (position, pedal) in marks {
+			let number = pedal.number.unwrap_or(1);
+			let current = open.iter().position(|&(n, _)| n == number);
+			let stops = match pedal.pedal_type {
+				PedalType::Stop | PedalType::Discontinue | PedalType::Change => true,
+				PedalType::Start | PedalType::Sostenuto | PedalType::Resume | PedalType::Continue => false,
+			};
+			let mut sostenuto = pedal.pedal_type == PedalType::Sostenuto;
+			if let (true, Some(index)) = (stops, current) {
+				let (_, span) = open.remove(index);
+				spans[span].stop = Some(position.clone());
+				sostenuto = spans[span].sostenuto;
+			}
+			let starts = match pedal.pedal_type {
+				PedalType::Start | PedalType::Sostenuto | PedalType::Resume | PedalType::Change => true,
+				PedalType::Stop | PedalType::Discontinue | PedalType::Continue => false,
+			};
+			if starts && open.iter().all(|&(n, _)| n != number) {
+				open.push((number, spans.len()));
+				spans.push(PedalSpan {
+					number,
+					sostenuto,
+					start: position,
+					stop: None,
+				});
+			}
+		}
+		spans
+	}
+}
+
+/// The arpeggiate type indicates that this note is part of an arpeggiated chord. The number attribute can be used to distinguish between two simultaneous chords arpeggiated separately (different numbers) or together (same number). The direction attribute is used if there is an arrow on the arpeggio sign. By default, arpeggios go from the lowest to highest note.  The length of the sign can be determined from the position attributes for the arpeggiate elements used with the top and bottom notes of the arpeggiated chord. If the unbroken attribute is set to yes, it indicates that the arpeggio continues onto another staff within the part. This serves as a hint to applications and is not required for cross-staff arpeggios.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Arpeggiate {
+	pub number: Option<NumberLevel>,
+	pub direction: Option<UpDown>,
+	pub unbroken: Option<YesNo>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	pub placement: Option<AboveBelow>,
+	pub color: Option<Color>,
+	pub id: Option<String>,
+}
+
+impl Arpeggiate {
+	/// Groups simultaneous notes by the arpeggio they belong to. Notes whose arpeggiate elements share a number (or all have none) are rolled together; the groups are returned in order of their first note.
+	pub fn group<'a, T, I>(notes: I) -> Vec<Vec<T>>
+	where
+		I: IntoIterator<Item = (T, &'a Arpeggiate)>,
+	{
+		let mut groups: Vec<(Option<NumberLevel>, Vec<T>)> = Vec::new();
+		for (note, arpeggiate) in notes {
+			match groups.iter_mut().find(|(number, _)| *number == arpeggiate.number) {
+				Some((_, group)) => group.push(note),
+				None => groups.push((arpeggiate.number, vec![note])),
+			}
+		}
+		groups.into_iter().map(|(_, group)| group).collect()
+	}
+
+	/// Returns playback offsets for rolling the given chord pitches over the spread duration, in the order the pitches were given. The pitches are rolled from the lowest to the highest, or from the highest to the lowest if the direction is down, and the last pitch starts at the end of the spread.
+	pub fn rolled_offsets(&self, pitches: &[Pitch], spread: Duration) -> Vec<Duration> {
+		let mut order: Vec<usize> = (0..pitches.len()).collect();
+		order.sort_by(|&a, &b| {
+			pitches[a]
+				.semitone_number()
+				.partial_cmp(&pitches[b].semitone_number())
+				.unwrap_or(std::cmp::Ordering::Equal)
+		});
+		if self.direction == Some(UpDown::Down) {
+			order.reverse();
+		}
+		let steps = (pitches.len() as u64).saturating_sub(1).max(1);
+		let mut offsets = vec![Duration::ZERO; pitches.len()];
+		for (rank, &index) in order.iter().enumerate() {
+			offsets[index] = Duration::new(spread.numerator() * rank as u64, spread.denominator() * steps);
+		}
+		offsets
+	}
+}
+
+/// The non-arpeggiate type indicates that this note is at the top or bottom of a bracket indicating to not arpeggiate these notes. Since this does not involve playback, it is only used on the top or bottom notes, not on each note as for the arpeggiate type.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct NonArpeggiate {
+	#[serde(rename = "type")]
+	pub non_arpeggiate_type: TopBottom,
+	pub number: Option<NumberLevel>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	pub placement: Option<AboveBelow>,
+	pub color: Option<Color>,
+	pub id: Option<String>,
+}
+
+/// Glissando and slide types both indicate rapidly moving from one pitch to the other so that individual notes are not discerned. A glissando sounds the distinct notes in between the two pitches and defaults to a wavy line. The optional text is printed alongside the line.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Glissando {
+	#[serde(rename = "$value", default)]
+	pub text: String,
+	#[serde(rename = "type")]
+	pub glissando_type: StartStop,
+	pub number: Option<NumberLevel>,
+	#[serde(rename = "line-type")]
+	pub line_type: Option<LineType>,
+	#[serde(rename = "dash-length")]
+	pub dash_length: Option<Tenths>,
+	#[serde(rename = "space-length")]
+	pub space_length: Option<Tenths>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub id: Option<String>,
+}
+
+impl Glissando {
+	/// Returns the chromatic steps sounded by a glissando from the start pitch to the stop pitch, as semitone numbers. The start pitch is included and the stop pitch, which sounds as its own note, is not. Returns None if the pitches are too far apart to list the steps.
+	pub fn steps(start: &Pitch, stop: &Pitch) -> Option<Vec<Semitones>> {
+		let from = start.semitone_number();
+		let to = stop.semitone_number();
+		let count = (to - from).abs().ceil();
+		if count.is_nan() || count > MAX_EXPANSION as f64 {
+			return None;
+		}
+		let direction = if to < from { -1.0 } else { 1.0 };
+		Some((0..count as usize).map(|step| from + direction * step as Semitones).collect())
+	}
+}
+
+/// Glissando and slide types both indicate rapidly moving from one pitch to the other so that individual notes are not discerned. A slide is continuous between the two pitches and defaults to a solid line. The optional text for a is printed alongside the line.
+///
+/// The bend-sound attributes are used as for the bend element: the beats attribute refers to the number of discrete elements (like MIDI pitch bends) used to represent the continuous slide. The first-beat attribute indicates the percentage of the duration for starting the slide; the last-beat attribute indicates the percentage for ending it. The defaults are accelerate no, beats 4, first-beat 25, and last-beat 75.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Slide {
+	#[serde(rename = "$value", default)]
+	pub text: String,
+	#[serde(rename = "type")]
+	pub slide_type: StartStop,
+	pub number: Option<NumberLevel>,
+	#[serde(rename = "line-type")]
+	pub line_type: Option<LineType>,
+	#[serde(rename = "dash-length")]
+	pub dash_length: Option<Tenths>,
+	#[serde(rename = "space-length")]
+	pub space_length: Option<Tenths>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub accelerate: Option<YesNo>,
+	pub beats: Option<TrillBeats>,
+	#[serde(rename = "first-beat")]
+	pub first_beat: Option<Percent>,
+	#[serde(rename = "last-beat")]
+	pub last_beat: Option<Percent>,
+	pub id: Option<String>,
+}
+
+impl Slide {
+	/// Returns a pitch-bend curve for a slide from the start pitch to the stop pitch, as pairs of a fraction of the start note's duration and a bend in semitones from the start pitch. The curve has one point per beat, spread from first-beat to last-beat percent of the duration, with the points getting closer together when accelerate is yes. Returns None if the number of beats is too large to list.
+	pub fn bend_curve(&self, start: &Pitch, stop: &Pitch) -> Option<Vec<(f64, Semitones)>> {
+		let beats = self.beats.unwrap_or(4.0).max(2.0).round();
+		if beats > MAX_EXPANSION as f64 {
+			return None;
+		}
+		let beats = beats as usize;
+		let first = f64::from(self.first_beat.unwrap_or(25)) / 100.0;
+		let last = f64::from(self.last_beat.unwrap_or(75)) / 100.0;
+		let interval = stop.semitone_number() - start.semitone_number();
+		let curve = (0..beats)
+			.map(|beat| {
+				let progress = beat as f64 / (beats - 1) as f64;
+				let time = if self.accelerate == Some(YesNo::Yes) { progress.sqrt() } else { progress };
+				(first + (last - first) * time, interval * progress)
+			})
+			.collect();
+		Some(curve)
+	}
+}
+
+/// A note played when expanding a tremolo: the note carrying the tremolo, or the stop note of a double tremolo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TremoloNote {
+	Start,
+	Stop,
+}
+
+/// The tremolo ornament can be used to indicate single-note, double-note, or unmeasured tremolos. Single-note tremolos use the single type, double-note tremolos use the start and stop types, and unmeasured tremolos use the unmeasured type. The default is "single" for compatibility with Version 1.1. The text of the element indicates the number of tremolo marks and is an integer from 0 to 8. Note that the number of attached beams is not included in this value, but is represented separately using the beam element. The value should be 0 for unmeasured tremolos.
+///
+/// When using double-note tremolos, the duration of each note in the tremolo should correspond to half of the notated type value. A time-modification element should also be added with an actual-notes value of 2 and a normal-notes value of 1. If used within a tuplet, this 2/1 ratio should be multiplied by the existing tuplet ratio.
+///
+/// The smufl attribute specifies the glyph to use from the SMuFL Tremolos range for an unmeasured tremolo. It is ignored for other tremolo types. The SMuFL buzzRoll glyph is used by default if the attribute is missing.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Tremolo {
+	#[serde(rename = "$value")]
+	pub marks: TremoloMarks,
+	#[serde(rename = "type")]
+	pub tremolo_type: Option<TremoloType>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+	pub smufl: Option<SmuflGlyphName>,
+	pub id: Option<String>,
+}
+
+impl Tremolo {
+	/// Returns the tremolo type, which is single if not specified.
+	pub fn tremolo_type(&self) -> TremoloType {
+		self.tremolo_type.unwrap_or_default()
+	}
+
+	/// Returns the duration of each repeated note of a measured tremolo on a note of the given type. The tremolo marks add to the flags or beams of the note type, so two marks on an eighth note give 32nd notes. Returns None for unmeasured tremolos and for more marks than a duration can represent.
+	pub fn stroke_duration(&self, note_type: NoteTypeValue) -> Option<Duration> {
+		if self.tremolo_type() == TremoloType::Unmeasured {
+			return None;
+		}
+		let flags = match note_type.beat_type() {
+			Some(beat_type) if beat_type >= 8 => beat_type.trailing_zeros() - 2,
+			_ => 0,
+		};
+		let strokes = u32::from(self.marks) + flags;
+		if strokes == 0 {
+			return Duration::from_note_type(note_type, 0);
+		}
+		Duration::checked_new(1, 1u64.checked_shl(strokes + 2)?)
+	}
+
+	/// Expands a measured tremolo into the notes played, given the duration and notated type of the note carrying the tremolo. A single tremolo repeats the start note for the note's duration. A double tremolo is expanded on its start note and fills the duration of both notes, alternating between them starting with the start note; its stop note expands to nothing. Returns None for unmeasured tremolos, when the duration is not a whole number of strokes and when there are too many strokes to list.
+	pub fn expand(&self, duration: Duration, note_type: NoteTypeValue) -> Option<Vec<(Duration, TremoloNote)>> {
+		let stroke = self.stroke_duration(note_type)?;
+		let total = match self.tremolo_type() {
+			TremoloType::Single => duration,
+			TremoloType::Start => duration.checked_add(duration)?,
+			TremoloType::Stop => return Some(Vec::new()),
+			TremoloType::Unmeasured => return None,
+		};
+		let numerator = u128::from(total.numerator()) * u128::from(stroke.denominator());
+		let denominator = u128::from(total.denominator()) * u128::from(stroke.numerator());
+		if !numerator.is_multiple_of(denominator) || numerator / denominator > MAX_EXPANSION as u128 {
+			return None;
+		}
+		let alternate = self.tremolo_type() == TremoloType::Start;
+		Some(
+			(0..(numerator / denominator) as usize)
+				.map(|index| {
+					let note = if alternate && index % 2 == 1 { TremoloNote::Stop } else { TremoloNote::Start };
+					(stroke, note)
+				})
+				.collect(),
+		)
+	}
+}
+
+/// The empty-placement type represents an empty element with print-style and placement attributes.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct EmptyPlacement {
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+}
+
+/// The strong-accent type indicates a vertical accent mark. The type attribute indicates if the point of the accent is down or up.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct StrongAccent {
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+	#[serde(rename = "type")]
+	pub strong_accent_type: Option<UpDown>,
+}
+
+impl StrongAccent {
+	/// Returns whether the point of the accent is up or down, which is up if not specified.
+	pub fn strong_accent_type(&self) -> UpDown {
+		self.strong_accent_type.unwrap_or(UpDown::Up)
+	}
+}
+
+/// The empty-line type represents an empty element with line-shape, line-type, line-length, dashed-formatting, print-style and placement attributes.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct EmptyLine {
+	#[serde(rename = "line-shape")]
+	pub line_shape: Option<LineShape>,
+	#[serde(rename = "line-type")]
+	pub line_type: Option<LineType>,
+	#[serde(rename = "line-length")]
+	pub line_length: Option<LineLength>,
+	#[serde(rename = "dash-length")]
+	pub dash_length: Option<Tenths>,
+	#[serde(rename = "space-length")]
+	pub space_length: Option<Tenths>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+}
+
+/// The breath-mark element indicates a place to take a breath.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct BreathMark {
+	#[serde(rename = "$value", default)]
+	pub value: BreathMarkValue,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+}
+
+/// The caesura element indicates a slight pause. It is notated using a "railroad tracks" symbol or other variations specified in the element content.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Caesura {
+	#[serde(rename = "$value", default)]
+	pub value: CaesuraValue,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+}
+
+/// The fermata text content represents the shape of the fermata sign. An empty fermata element represents a normal fermata. The fermata type is upright if not specified.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Fermata {
+	#[serde(rename = "$value", default)]
+	pub value: FermataShape,
+	#[serde(rename = "type")]
+	pub fermata_type: Option<UprightInverted>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub id: Option<String>,
+}
+
+impl Fermata {
+	/// Returns the shape of the fermata sign, treating the empty value as normal.
+	pub fn shape(&self) -> FermataShape {
+		match self.value {
+			FermataShape::Empty => FermataShape::Normal,
+			shape => shape,
+		}
+	}
+
+	/// Returns whether the fermata is upright or inverted, which is upright if not specified.
+	pub fn fermata_type(&self) -> UprightInverted {
+		self.fermata_type.unwrap_or_default()
+	}
+}
+
+/// The other-placement-text type represents a text element with print-style, placement, and smufl attribute groups. This type is used by MusicXML notation extension elements to allow specification of specific SMuFL glyphs without needed to add every glyph as a MusicXML element.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct OtherPlacementText {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+	pub smufl: Option<SmuflGlyphName>,
+}
+
+/// One articulation within an articulations element.
+///
+/// The accent element indicates a regular horizontal accent mark. The strong-accent element indicates a vertical accent mark. The staccato element is used for a dot articulation, as opposed to a stroke or a wedge. The tenuto element indicates a tenuto line symbol. The detached-legato element indicates the combination of a tenuto line and staccato dot symbol. The staccatissimo element is used for a wedge articulation, as opposed to a dot or a stroke. The spiccato element is used for a stroke articulation, as opposed to a dot or a wedge.
+///
+/// The scoop element is an indeterminate slide attached to a single note. The scoop appears before the main note and comes from below the main pitch. The plop element is an indeterminate slide attached to a single note. The plop appears before the main note and comes from above the main pitch. The doit element is an indeterminate slide attached to a single note. The doit appears after the main note and goes above the main pitch. The falloff element is an indeterminate slide attached to a single note. The falloff appears after the main note and goes below the main pitch.
+///
+/// The stress element indicates a stressed note. The unstress element indicates an unstressed note. It is often notated using a u-shaped symbol. The soft-accent element indicates a soft accent that is not as heavy as a normal accent. It is often notated as <>. It can be combined with other articulations to implement the first eight symbols in the SMuFL Articulation supplement range. The other-articulation element is used to define any articulations not yet in the MusicXML format.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Articulation {
+	Accent(EmptyPlacement),
+	StrongAccent(StrongAccent),
+	Staccato(EmptyPlacement),
+	Tenuto(EmptyPlacement),
+	DetachedLegato(EmptyPlacement),
+	Staccatissimo(EmptyPlacement),
+	Spiccato(EmptyPlacement),
+	Scoop(EmptyLine),
+	Plop(EmptyLine),
+	Doit(EmptyLine),
+	Falloff(EmptyLine),
+	BreathMark(BreathMark),
+	Caesura(Caesura),
+	Stress(EmptyPlacement),
+	Unstress(EmptyPlacement),
+	SoftAccent(EmptyPlacement),
+	OtherArticulation(OtherPlacementText),
+}
+
+/// Articulations and accents are grouped together here.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Articulations {
+	#[serde(rename = "$value", default)]
+	pub content: Vec<Articulation>,
+	pub id: Option<String>,
+}
+
+/// A summary of the articulations on a note, for playback and analysis code that only needs to know which marks are present.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArticulationFlags {
+	pub accent: bool,
+	pub strong_accent: bool,
+	pub staccato: bool,
+	pub tenuto: bool,
+	pub detached_legato: bool,
+	pub staccatissimo: bool,
+	pub spiccato: bool,
+	pub stress: bool,
+	pub unstress: bool,
+	pub soft_accent: bool,
+	pub slide: bool,
+	pub breath_mark: bool,
+	pub caesura: bool,
+	pub other: bool,
+}
+
+impl ArticulationFlags {
+	/// Returns whether any kind of accent is present.
+	pub fn is_accented(&self) -> bool {
+		self.accent || self.strong_accent || self.soft_accent || self.stress
+	}
+
+	/// Returns the conventional fraction of the notated duration that is sounded: a quarter for staccatissimo, a half for staccato and spiccato, three quarters for detached-legato, and the full duration otherwise, including for tenuto.
+	pub fn duration_factor(&self) -> f64 {
+		if self.staccatissimo {
+			0.25
+		} else if self.staccato || self.spiccato {
+			0.5
+		} else if self.detached_legato {
+			0.75
+		} else {
+			1.0
+		}
+	}
+}
+
+impl Articulations {
+	/// Returns a summary of which articulations are present.
+	pub fn articulation_flags(&self) -> ArticulationFlags {
+		let mut flags = ArticulationFlags::default();
+		for articulation in &self.content {
+			match articulation {
+				Articulation::Accent(_) => flags.accent = true,
+				Articulation::StrongAccent(_) => flags.strong_accent = true,
+				Articulation::Staccato(_) => flags.staccato = true,
+				Articulation::Tenuto(_) => flags.tenuto = true,
+				Articulation::DetachedLegato(_) => flags.detached_legato = true,
+				Articulation::Staccatissimo(_) => flags.staccatissimo = true,
+				Articulation::Spiccato(_) => flags.spiccato = true,
+				Articulation::Scoop(_) | Articulation::Plop(_) | Articulation::Doit(_) | Articulation::Falloff(_) => flags.slide = true,
+				Articulation::BreathMark(_) => flags.breath_mark = true,
+				Articulation::Caesura(_) => flags.caesura = true,
+				Articulation::Stress(_) => flags.stress = true,
+				Articulation::Unstress(_) => flags.unstress = true,
+				Articulation::SoftAccent(_) => flags.soft_accent = true,
+				Articulation::OtherArticulation(_) => flags.other = true,
+			}
+		}
+		flags
+	}
+}
+
+/// The empty-placement-smufl type represents an empty element with print-style, placement, and smufl attributes.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct EmptyPlacementSmufl {
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+	pub smufl: Option<SmuflGlyphName>,
+}
+
+/// The placement-text type represents a text element with print-style and placement attribute groups.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct PlacementText {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+}
+
+/// The harmonic type indicates natural and artificial harmonics. Allowing the type of pitch to be specified, combined with controls for appearance/playback differences, allows both the notation and the sound to be represented. Artificial harmonics can add a notated touching pitch; artificial pinch harmonics will usually not notate a touching pitch. The attributes for the harmonic element refer to the use of the circular harmonic symbol, typically but not always used with natural harmonics.
+///
+/// The natural element indicates that this is a natural harmonic. These are usually notated at base pitch rather than sounding pitch. The artificial element indicates that this is an artificial harmonic. The base pitch is the pitch at which the string is played before touching to create the harmonic. The touching-pitch is the pitch at which the string is touched lightly to produce the harmonic. The sounding-pitch is the pitch which is heard when playing the harmonic.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Harmonic {
+	pub natural: Option<Empty>,
+	pub artificial: Option<Empty>,
+	#[serde(rename = "base-pitch")]
+	pub base_pitch: Option<Empty>,
+	#[serde(rename = "touching-pitch")]
+	pub touching_pitch: Option<Empty>,
+	#[serde(rename = "sounding-pitch")]
+	pub sounding_pitch: Option<Empty>,
+	#[serde(rename = "print-object")]
+	pub print_object: Option<YesNo>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+}
+
+/// Fingering is typically indicated 1,2,3,4,5. Multiple fingerings may be given, typically to substitute fingerings in the middle of a note. The substitution and alternate values are "no" if the attribute is not present. For guitar and other fretted instruments, the fingering element represents the fretting finger; the pluck element represents the plucking finger.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Fingering {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	pub substitution: Option<YesNo>,
+	pub alternate: Option<YesNo>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+}
+
+/// The fret element is used with tablature notation and chord diagrams. Fret numbers start with 0 for an open string and 1 for the first fret.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Fret {
+	#[serde(rename = "$value")]
+	pub value: u64,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+}
+
+/// The string type is used with tablature notation, regular notation (where it is often circled), and chord diagrams. String numbers start with 1 for the highest pitched full-length string.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct StringElement {
+	#[serde(rename = "$value")]
+	pub value: StringNumber,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+}
+
+/// The hammer-on and pull-off elements are used in guitar and fretted instrument notation. Since a single slur can be marked over many notes, the hammer-on and pull-off elements are separate so the individual pair of notes can be specified. The element content can be used to specify how the hammer-on or pull-off should be notated. An empty element leaves this choice up to the application.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct HammerOnPullOff {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	#[serde(rename = "type")]
+	pub hammer_on_pull_off_type: StartStop,
+	pub number: Option<NumberLevel>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+}
+
+/// The release type indicates that a bend is a release rather than a normal bend or pre-bend. The offset attribute specifies where the release starts in terms of divisions relative to the current note. The first-beat and last-beat attributes of the parent bend element are relative to the original note position, not this offset value.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Release {
+	pub offset: Option<Divisions>,
+}
+
+/// The bend type is used in guitar notation and tablature. A single note with a bend and release will contain two bend elements: the first to represent the bend and the second to represent the release. The shape attribute distinguishes between the angled bend symbols commonly used in standard notation and the curved bend symbols commonly used in both tablature and standard notation.
+///
+/// The bend-alter element indicates the number of semitones in the bend, similar to the alter element. As with the alter element, numbers like 0.5 can be used to indicate microtones. Negative values indicate pre-bends or releases. The pre-bend and release elements are used to distinguish what is intended. Because the bend-alter element represents the number of steps in the bend, a release after a bend has a negative bend-alter value, not a zero value.
+///
+/// The pre-bend element indicates that a bend is a pre-bend rather than a normal bend or a release. The with-bar element indicates that the bend is to be done at the bridge with a whammy or vibrato bar. The content of the element indicates how this should be notated. Content values of "scoop" and "dip" refer to the SMuFL guitarVibratoBarScoop and guitarVibratoBarDip glyphs.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Bend {
+	#[serde(rename = "bend-alter")]
+	pub bend_alter: Semitones,
+	#[serde(rename = "pre-bend")]
+	pub pre_bend: Option<Empty>,
+	pub release: Option<Release>,
+	#[serde(rename = "with-bar")]
+	pub with_bar: Option<PlacementText>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub accelerate: Option<YesNo>,
+	pub beats: Option<TrillBeats>,
+	#[serde(rename = "first-beat")]
+	pub first_beat: Option<Percent>,
+	#[serde(rename = "last-beat")]
+	pub last_beat: Option<Percent>,
+	pub shape: Option<BendShape>,
+}
+
+/// The tap type indicates a tap on the fingerboard. The text content allows specification of the notation; + and T are common choices. If the element is empty, the hand attribute is used to specify the symbol to use. The hand attribute is ignored if the tap glyph is already specified by the text content. If neither text nor the hand attribute are present, the display is application-specific.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Tap {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	pub hand: Option<TapHand>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+}
+
+/// The heel and toe elements are used with organ pedals. The substitution value is "no" if the attribute is not present.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct HeelToe {
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+	pub substitution: Option<YesNo>,
+}
+
+/// The harmon-closed type represents whether the harmon mute is closed, open, or half-open. The optional location attribute indicates which portion of the symbol is filled in when the element value is half.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct HarmonClosed {
+	#[serde(rename = "$value")]
+	pub value: HarmonClosedValue,
+	pub location: Option<HarmonClosedLocation>,
+}
+
+/// The harmon-mute type represents the symbols used for harmon mutes in brass notation.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct HarmonMute {
+	#[serde(rename = "harmon-closed")]
+	pub harmon_closed: HarmonClosed,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub placement: Option<AboveBelow>,
+}
+
+/// One indication within a technical element.
+///
+/// The up-bow and down-bow elements represent bowing marks. The open-string element represents the zero-shaped open string symbol. The thumb-position element represents the thumb position symbol, a circle with a line, where the line does not come within the circle. It is distinct from the snap pizzicato symbol, where the line comes inside the circle. The pluck element is used to specify the plucking fingering on a fretted instrument, where the fingering element refers to the fretting fingering. The double-tongue and triple-tongue elements represent double and triple tonguing symbols. The stopped element represents the stopped symbol, which looks like a plus sign. The snap-pizzicato element represents the snap pizzicato symbol, a circle with a line, where the line comes inside the circle. The fingernails element is used in notation for harp and other plucked string instruments.
+///
+/// The brass-bend element represents the u-shaped bend symbol used in brass notation, distinct from the bend element used in guitar music. The flip element represents the flip symbol used in brass notation. The smear element represents the tilde-shaped smear symbol used in brass notation. The open element represents the open symbol, which looks like a circle. The half-muted element represents the half-muted symbol, which looks like a circle with a plus sign inside. The golpe element represents the golpe symbol that is used for tapping the pick guard in guitar music. The other-technical element is used to define any technical indications not yet in the MusicXML format.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TechnicalContent {
+	UpBow(EmptyPlacement),
+	DownBow(EmptyPlacement),
+	Harmonic(Harmonic),
+	OpenString(EmptyPlacement),
+	ThumbPosition(EmptyPlacement),
+	Fingering(Fingering),
+	Pluck(PlacementText),
+	DoubleTongue(EmptyPlacement),
+	TripleTongue(EmptyPlacement),
+	Stopped(EmptyPlacementSmufl),
+	SnapPizzicato(EmptyPlacement),
+	Fret(Fret),
+	String(StringElement),
+	HammerOn(HammerOnPullOff),
+	PullOff(HammerOnPullOff),
+	Bend(Bend),
+	Tap(Tap),
+	Heel(HeelToe),
+	Toe(HeelToe),
+	Fingernails(EmptyPlacement),
+	Hole(Hole),
+	Arrow(Arrow),
+	Handbell(Handbell),
+	BrassBend(EmptyPlacement),
+	Flip(EmptyPlacement),
+	Smear(EmptyPlacement),
+	Open(EmptyPlacementSmufl),
+	HalfMuted(EmptyPlacementSmufl),
+	HarmonMute(HarmonMute),
+	Golpe(EmptyPlacement),
+	OtherTechnical(OtherPlacementText),
+}
+
+/// Technical indications give performance information for individual instruments.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Technical {
+	#[serde(rename = "$value", default)]
+	pub content: Vec<TechnicalContent>,
+	pub id: Option<String>,
+}
+
+/// The group-name type describes the name or abbreviation of a part-group element. Formatting attributes in the group-name type are deprecated in Version 2.0 in favor of the new group-name-display and group-abbreviation-display elements.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct GroupName {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub justify: Option<LeftCenterRight>,
+}
+
+/// The group-symbol type indicates how the symbol for a group is indicated in the score.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct GroupSymbol {
+	#[serde(rename = "$value")]
+	pub value: GroupSymbolValue,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	pub color: Option<Color>,
+}
+
+/// The group-barline type indicates if the group should have common barlines.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct GroupBarline {
+	#[serde(rename = "$value")]
+	pub value: GroupBarlineValue,
+	pub color: Option<Color>,
+}
+
+/// The part-group element indicates groupings of parts in the score, usually indicated by braces and brackets. Braces that are used for multi-staff parts should be defined in the attributes element for that part. The part-group start element appears before the first score-part in the group. The part-group stop element appears after the last score-part in the group.
+///
+/// The number attribute is used to distinguish overlapping and nested part-groups, not the sequence of groups. As with parts, groups can have a name and abbreviation. Values for the child elements are ignored at the stop of a group.
+///
+/// A part-group element is not needed for a single multi-staff part. By default, multi-staff parts include a brace symbol and (if appropriate given the bar-style) common barlines. The symbol formatting for a multi-staff part can be more fully specified using the part-symbol element.
+///
+/// The group-time element indicates that the displayed time signatures should stretch across all parts and staves in the group.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PartGroup {
+	#[serde(rename = "group-name")]
+	pub group_name: Option<GroupName>,
+	#[serde(rename = "group-abbreviation")]
+	pub group_abbreviation: Option<GroupName>,
+	#[serde(rename = "group-symbol")]
+	pub group_symbol: Option<GroupSymbol>,
+	#[serde(rename = "group-barline")]
+	pub group_barline: Option<GroupBarline>,
+	#[serde(rename = "group-time")]
+	pub group_time: Option<Empty>,
+	#[serde(rename = "type")]
+	pub part_group_type: StartStop,
+	pub number: Option<String>,
+}
+
+/// An entry of the part-list element, as needed to resolve part groups: either a part-group element or a score-part.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PartListEntry<'a> {
+	PartGroup(&'a PartGroup),
+	ScorePart,
+}
+
+/// A part group resolved to the range of parts it spans, as drawn by a renderer at the left of each system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupBracket {
+	/// The number attribute of the part-group element, which is "1" if not specified.
+	pub number: String,
+	pub symbol: GroupSymbolValue,
+	pub barline: Option<GroupBarlineValue>,
+	/// The index of the first score-part in the group, counting from 0.
+	pub first_part: usize,
+	/// The index of the last score-part in the group.
+	pub last_part: usize,
+	/// The number of groups enclosing this one. Outer groups are drawn further to the left.
+	pub depth: usize,
+}
+
+impl PartGroup {
+	/// Returns the number attribute, which is "1" if not specified.
+	pub fn number(&self) -> &str {
+		self.number.as_deref().unwrap_or("1")
+	}
+
+	/// Resolves the part-group elements of a part-list into the groups they describe, ordered by their first part and then from outermost to innermost. The symbol, barline and other values are taken from the start element. A stop element closes the open group with the same number. Groups that are never stopped extend to the last part, and groups containing no parts are dropped.
+	pub fn brackets<'a, I: IntoIterator<Item = PartListEntry<'a>>>(entries: I) -> Vec<GroupBracket> {
+		let mut brackets: Vec<GroupBracket> = Vec::new();
+		let mut open: Vec<(&'a PartGroup, usize)> = Vec::new();
+		let mut parts = 0;
+		for entry in entries {
+			match entry {
+				PartListEntry::ScorePart => parts += 1,
+				PartListEntry::PartGroup(group) => match group.part_group_type {
+					StartStop::Start => open.push((group, parts)),
+					StartStop::Stop => {
+						if let Some(index) = open.iter().rposition(|(start, _)| start.number() == group.number()) {
+							let (start, first_part) = open.remove(index);
+							brackets.extend(start.bracket(first_part, parts));
+						}
+					},
+				},
+			}
+		}
+		for (start, first_part) in open {
+			brackets.extend(start.bracket(first_part, parts));
+		}
+		brackets.sort_by(|a, b| a.first_part.cmp(&b.first_part).then(b.last_part.cmp(&a.last_part)));
+		for index in 0..brackets.len() {
+			let (first_part, last_part) = (brackets[index].first_part, brackets[index].last_part);
+			brackets[index].depth = brackets[..index]
+				.iter()
+				.filter(|outer| outer.first_part <= first_part && last_part <= outer.last_part)
+				.count();
+		}
+		brackets
+	}
+
+	fn bracket(&self, first_part: usize, end: usize) -> Option<GroupBracket> {
+		if end == first_part {
+			return None;
+		}
+		Some(GroupBracket {
+			number: self.number().to_string(),
+			symbol: self.group_symbol.as_ref().map_or(GroupSymbolValue::None, |symbol| symbol.value),
+			barline: self.group_barline.as_ref().map(|barline| barline.value),
+			first_part,
+			last_part: end - 1,
+			depth: 0,
+		})
+	}
+}
+
+/// Margins, page sizes, and distances are all measured in tenths to keep MusicXML data in a consistent coordinate system as much as possible. The translation to absolute units is done with the scaling type, which specifies how many millimeters are equal to how many tenths. For a staff height of 7 mm, millimeters would be set to 7 while tenths is set to 40. The ability to set a formula rather than a single scaling factor helps avoid roundoff errors.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+pub struct Scaling {
+	pub millimeters: Millimeters,
+	pub tenths: Tenths,
+}
+
+/// The empty-print-object-style-align type represents an empty element with print-object and print-style-align attribute groups.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct EmptyPrintObjectStyleAlign {
+	#[serde(rename = "print-object")]
+	pub print_object: Option<YesNo>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+}
+
+/// The system-dividers element indicates the presence or absence of system dividers (also known as system separation marks) between systems displayed on the same page. Dividers on the left and right side of the page are controlled by the left-divider and right-divider elements respectively. The default vertical position is half the system-distance value from the top of the system that is below the divider. The default horizontal position is the left and right system margin, respectively.
+///
+/// When used in the print element, the system-dividers element affects the dividers that would appear between the current system and the previous system.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SystemDividers {
+	#[serde(rename = "left-divider")]
+	pub left_divider: EmptyPrintObjectStyleAlign,
+	#[serde(rename = "right-divider")]
+	pub right_divider: EmptyPrintObjectStyleAlign,
+}
+
+/// System margins are relative to the page margins. Positive values indent and negative values reduce the margin size.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+pub struct SystemMargins {
+	#[serde(rename = "left-margin")]
+	pub left_margin: Tenths,
+	#[serde(rename = "right-margin")]
+	pub right_margin: Tenths,
+}
+
+/// A system is a group of staves that are read and played simultaneously. System layout includes left and right margins and the vertical distance from the previous system. The system distance is measured from the bottom line of the previous system to the top line of the current system. It is ignored for the first system on a page. The top system distance is measured from the page's top margin to the top line of the first system. It is ignored for all but the first system on a page.
+///
+/// Sometimes the sum of measure widths in a system may not equal the system width specified by the layout elements due to roundoff or other errors. The behavior when reading MusicXML files in these cases is application-dependent. For instance, applications may find that the system layout data is more reliable than the sum of the measure widths, and adjust the measure widths accordingly.
+///
+/// When used in the defaults element, the system-layout element defines a default appearance for all systems in the score. If no system-layout element is present in the defaults element, default system layout values are chosen by the application.
+///
+/// When used in the print element, the system-layout element affects the appearance of the current system only. All other systems use the default values as determined by the defaults element. If any child elements are missing from the system-layout element in a print element, the values determined by the defaults element are used there as well. This type of system-layout element need only be read from or written to the first visible part in the score.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SystemLayout {
+	#[serde(rename = "system-margins")]
+	pub system_margins: Option<SystemMargins>,
+	#[serde(rename = "system-distance")]
+	pub system_distance: Option<Tenths>,
+	#[serde(rename = "top-system-distance")]
+	pub top_system_distance: Option<Tenths>,
+	#[serde(rename = "system-dividers")]
+	pub system_dividers: Option<SystemDividers>,
+}
+
+impl SystemLayout {
+	/// Returns this layout with missing values taken from a default layout, as for a system-layout element in a print element.
+	pub fn or(&self, defaults: &SystemLayout) -> SystemLayout {
+		SystemLayout {
+			system_margins: self.system_margins.or(defaults.system_margins),
+			system_distance: self.system_distance.or(defaults.system_distance),
+			top_system_distance: self.top_system_distance.or(defaults.top_system_distance),
+			system_dividers: self.system_dividers.clone().or_else(|| defaults.system_dividers.clone()),
+		}
+	}
+}
+
+/// Staff layout includes the vertical distance from the bottom line of the previous staff in this system to the top line of the staff specified by the number attribute. The optional number attribute refers to staff numbers within the part, from top to bottom on the system. A value of 1 is used if not present.
+///
+/// When used in the defaults element, the values apply to all systems in all parts. When used in the print element, the values apply to the current system only. This value is ignored for the first staff in a system.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct StaffLayout {
+	#[serde(rename = "staff-distance")]
+	pub staff_distance: Option<Tenths>,
+	pub number: Option<StaffNumber>,
+}
+
+/// The defaults type specifies score-wide defaults for scaling; whether or not the file is a concert score; layout; and default values for the music font, word font, lyric font, and lyric language. Except for the concert-score element, if any defaults are missing, the choice of what to use is determined by the application.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Defaults {
+	pub scaling: Option<Scaling>,
+	#[serde(rename = "concert-score")]
+	pub concert_score: Option<Empty>,
+	#[serde(rename = "system-layout")]
+	pub system_layout: Option<SystemLayout>,
+	#[serde(rename = "staff-layout", default)]
+	pub staff_layout: Vec<StaffLayout>,
+}
+
+impl Defaults {
+	/// Converts a distance in tenths to millimeters using the score's scaling. Returns None if the scaling is not specified.
+	pub fn tenths_to_mm(&self, tenths: Tenths) -> Option<Millimeters> {
+		self.scaling.map(|scaling| scaling.tenths_to_mm(tenths))
+	}
+
+	/// Returns the default distance from the previous staff for the given staff number, if specified.
+	pub fn staff_distance(&self, staff: StaffNumber) -> Option<Tenths> {
+		self.staff_layout
+			.iter()
+			.find(|layout| layout.number.unwrap_or(1) == staff)
+			.and_then(|layout| layout.staff_distance)
+	}
+}
+
+/// The print type contains general printing parameters, including layout elements. The part-name-display and part-abbreviation-display elements may also be used here to change how a part name or abbreviation is displayed over the course of a piece. They take effect when the current measure or a succeeding measure starts a new system.
+///
+/// Layout group elements in a print element only apply to the current page, system, or staff. Music that follows continues to take the default values from the layout determined by the defaults element.
+///
+/// The staff-spacing attribute specifies spacing between multiple staves in tenths of staff space. The new-system and new-page attributes indicate whether to force a system or page break, or to force the current music onto the same system or page as the preceding music. Normally this is the first music data within a measure. If used in multi-part music, the attributes should be placed in the same positions within each part, or the results are undefined. The page-number attribute sets the number of a new page; it is ignored if new-page is not "yes". Version 2.0 adds a blank-page attribute. This is a positive integer value that specifies the number of blank pages to insert before the current measure. It is ignored if new-page is not "yes". These blank pages have no music, but may have text or images specified by the credit element. This is used to allow a combination of pages that are all text, or all text and images, together with pages of music.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Print {
+	#[serde(rename = "system-layout")]
+	pub system_layout: Option<SystemLayout>,
+	#[serde(rename = "staff-layout", default)]
+	pub staff_layout: Vec<StaffLayout>,
+	#[serde(rename = "staff-spacing")]
+	pub staff_spacing: Option<Tenths>,
+	#[serde(rename = "new-system")]
+	pub new_system: Option<YesNo>,
+	#[serde(rename = "new-page")]
+	pub new_page: Option<YesNo>,
+	#[serde(rename = "blank-page")]
+	pub blank_page: Option<u64>,
+	#[serde(rename = "page-number")]
+	pub page_number: Option<String>,
+	pub id: Option<String>,
+}
+
+impl Print {
+	/// Returns the system layout in effect for the current system, taking missing values from the defaults.
+	pub fn system_layout(&self, defaults: &Defaults) -> Option<SystemLayout> {
+		match (&self.system_layout, &defaults.system_layout) {
+			(Some(layout), Some(default_layout)) => Some(layout.or(default_layout)),
+			(Some(layout), None) => Some(layout.clone()),
+			(None, default_layout) => default_layout.clone(),
+		}
+	}
+
+	/// Returns the distance from the previous staff for the given staff number in the current system, falling back to the defaults.
+	pub fn staff_distance(&self, staff: StaffNumber, defaults: &Defaults) -> Option<Tenths> {
+		self.staff_layout
+			.iter()
+			.find(|layout| layout.number.unwrap_or(1) == staff)
+			.and_then(|layout| layout.staff_distance)
+			.or_else(|| defaults.staff_distance(staff))
+	}
+}
+
+/// The pedal-tuning type specifies the tuning of a single harp pedal. The pedal-step element defines the pitch step for a single harp pedal. The pedal-alter element defines the chromatic alteration for a single harp pedal.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PedalTuning {
+	#[serde(rename = "pedal-step")]
+	pub pedal_step: Step,
+	#[serde(rename = "pedal-alter")]
+	pub pedal_alter: Semitones,
+}
+
+/// The harp-pedals type is used to create harp pedal diagrams. The pedal-step and pedal-alter elements use the same values as the step and alter elements. For easiest reading, the pedal-tuning elements should follow standard harp pedal order, with pedal-step values of D, C, B, E, F, G, and A.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct HarpPedals {
+	#[serde(rename = "pedal-tuning")]
+	pub pedal_tuning: Vec<PedalTuning>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+	pub id: Option<String>,
+}
+
+impl HarpPedals {
+	/// Returns the alteration the pedal for the given step is set to, if the diagram includes that pedal.
+	pub fn alter(&self, step: Step) -> Option<Semitones> {
+		self.pedal_tuning
+			.iter()
+			.find(|tuning| tuning.pedal_step == step)
+			.map(|tuning| tuning.pedal_alter)
+	}
+}
+
+/// The accordion-registration type is used for accordion registration symbols. These are circular symbols divided horizontally into high, middle, and low sections that correspond to 4', 8', and 16' pipes. Each accordion-high, accordion-middle, and accordion-low element represents the presence of one or more dots in the registration diagram. An accordion-registration element needs to have at least one of the child elements present.
+///
+/// The accordion-high element indicates the presence of a dot in the high (4') section of the registration symbol. This element is omitted if no dot is present. The accordion-middle element indicates the presence of 1 to 3 dots in the middle (8') section of the registration symbol. This element is omitted if no dots are present. The accordion-low element indicates the presence of a dot in the low (16') section of the registration symbol. This element is omitted if no dot is present.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct AccordionRegistration {
+	#[serde(rename = "accordion-high")]
+	pub accordion_high: Option<Empty>,
+	#[serde(rename = "accordion-middle")]
+	pub accordion_middle: Option<AccordionMiddle>,
+	#[serde(rename = "accordion-low")]
+	pub accordion_low: Option<Empty>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+	pub id: Option<String>,
+}
+
+/// The principal-voice type indicates the beginning and end of principal and secondary voices in 20th century music. The symbol attribute indicates the type of symbol used. When used at the start of the principal-voice element, this value is required. When used at the end of the principal-voice element, this value is optional. The content of the element is used when the symbol attribute is "none".
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PrincipalVoice {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	#[serde(rename = "type")]
+	pub principal_voice_type: StartStop,
+	pub symbol: PrincipalVoceSymbol,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+	pub id: Option<String>,
+}
+
+/// The accord type represents the tuning of a single string in the scordatura element. It uses the same group of elements as the staff-tuning element. Strings are numbered from high to low.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Accord {
+	#[serde(rename = "tuning-step")]
+	pub tuning_step: Step,
+	#[serde(rename = "tuning-alter")]
+	pub tuning_alter: Option<Semitones>,
+	#[serde(rename = "tuning-octave")]
+	pub tuning_octave: Octave,
+	pub string: StringNumber,
+}
+
+impl Accord {
+	/// Returns the pitch the string is tuned to.
+	pub fn pitch(&self) -> Pitch {
+		Pitch {
+			step: self.tuning_step,
+			alter: self.tuning_alter,
+			octave: self.tuning_octave,
+		}
+	}
+}
+
+/// Scordatura string tunings are represented by a series of accord elements, similar to the staff-tuning elements. Strings are numbered from high to low.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Scordatura {
+	pub accord: Vec<Accord>,
+	pub id: Option<String>,
+}
+
+impl Scordatura {
+	/// Returns the pitch the given string is tuned to, if the scordatura specifies it.
+	pub fn tuning(&self, string: StringNumber) -> Option<Pitch> {
+		self.accord.iter().find(|accord| accord.string == string).map(Accord::pitch)
+	}
+}
+
+/// The string-mute type represents string mute on and mute off symbols.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct StringMute {
+	#[serde(rename = "type")]
+	pub string_mute_type: OnOff,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+	pub id: Option<String>,
+}
+
+/// The image type is used to include graphical images in a score.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Image {
+	pub source: String,
+	#[serde(rename = "type")]
+	pub image_type: String,
+	pub height: Option<Tenths>,
+	pub width: Option<Tenths>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<ValignImage>,
+	pub id: Option<String>,
+}
+
+/// The staff-divide element represents the staff division arrow symbols found in SMuFL. The arrows can point down, up, or both up and down to indicate how the music is divided between the staves.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct StaffDivide {
+	#[serde(rename = "type")]
+	pub staff_divide_type: StaffDivideSymbol,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+	pub id: Option<String>,
+}
+
+/// Collapses runs of whitespace in text content into single spaces and trims the ends, unless xml:space is preserve.
+fn collapse_whitespace(value: &str, space: Option<XmlSpace>) -> String {
+	match space {
+		Some(XmlSpace::Preserve) => value.to_string(),
+		_ => value.split_whitespace().collect::<Vec<_>>().join(" "),
+	}
+}
+
+/// The formatted-text type represents a text element with text-formatting attributes.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct FormattedText {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	pub justify: Option<LeftCenterRight>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+	pub underline: Option<NumberOfLines>,
+	pub overline: Option<NumberOfLines>,
+	#[serde(rename = "line-through")]
+	pub line_through: Option<NumberOfLines>,
+	pub rotation: Option<RotationDegrees>,
+	#[serde(rename = "letter-spacing")]
+	pub letter_spacing: Option<NumberOrNormal>,
+	#[serde(rename = "line-height")]
+	pub line_height: Option<NumberOrNormal>,
+	#[serde(rename = "xml:lang")]
+	pub lang: Option<String>,
+	#[serde(rename = "xml:space")]
+	pub space: Option<XmlSpace>,
+	pub dir: Option<TextDirection>,
+	pub enclosure: Option<EnclosureShape>,
+}
+
+impl FormattedText {
+	/// Returns the text with whitespace handled according to the xml:space attribute.
+	pub fn text(&self) -> String {
+		collapse_whitespace(&self.value, self.space)
+	}
+}
+
+/// The formatted-text-id type represents a text element with text-formatting and id attributes. It is used by the words and rehearsal direction types and by credit-words.
+///
+/// The words element specifies a standard text direction. The left justification is assumed if not specified. Language is Italian ("it") by default. Enclosure is none by default.
+///
+/// The rehearsal element specifies letters, numbers, and section names that are notated in the score for reference during rehearsal. The enclosure is square if not specified. The language is Italian ("it") if not specified. Left justification is used if not specified.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct FormattedTextId {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	pub justify: Option<LeftCenterRight>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+	pub underline: Option<NumberOfLines>,
+	pub overline: Option<NumberOfLines>,
+	#[serde(rename = "line-through")]
+	pub line_through: Option<NumberOfLines>,
+	pub rotation: Option<RotationDegrees>,
+	#[serde(rename = "letter-spacing")]
+	pub letter_spacing: Option<NumberOrNormal>,
+	#[serde(rename = "line-height")]
+	pub line_height: Option<NumberOrNormal>,
+	#[serde(rename = "xml:lang")]
+	pub lang: Option<String>,
+	#[serde(rename = "xml:space")]
+	pub space: Option<XmlSpace>,
+	pub dir: Option<TextDirection>,
+	pub enclosure: Option<EnclosureShape>,
+	pub id: Option<String>,
+}
+
+impl FormattedTextId {
+	/// Returns the text with whitespace handled according to the xml:space attribute.
+	pub fn text(&self) -> String {
+		collapse_whitespace(&self.value, self.space)
+	}
+
+	/// Returns the language of the text, which is Italian if not specified as for words and rehearsal marks.
+	pub fn lang(&self) -> &str {
+		self.lang.as_deref().unwrap_or("it")
+	}
+}
+
+/// The formatted-symbol-id type represents a SMuFL musical symbol element with formatting and id attributes. It is used by the symbol direction type, which specifies a musical symbol using a canonical SMuFL glyph name. It is used when an occasional musical symbol is interspersed into text. It should not be used in place of semantic markup, such as metronome marks that mix text and symbols. Left justification is used if not specified. Enclosure is none if not specified.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct FormattedSymbolId {
+	#[serde(rename = "$value")]
+	pub value: SmuflGlyphName,
+	pub justify: Option<LeftCenterRight>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<Valign>,
+	pub underline: Option<NumberOfLines>,
+	pub overline: Option<NumberOfLines>,
+	#[serde(rename = "line-through")]
+	pub line_through: Option<NumberOfLines>,
+	pub rotation: Option<RotationDegrees>,
+	#[serde(rename = "letter-spacing")]
+	pub letter_spacing: Option<NumberOrNormal>,
+	#[serde(rename = "line-height")]
+	pub line_height: Option<NumberOrNormal>,
+	pub dir: Option<TextDirection>,
+	pub enclosure: Option<EnclosureShape>,
+	pub id: Option<String>,
+}
+
+/// The text-element-data type represents a syllable or portion of a syllable for lyric text underlay. A hyphen in the string content should only be used for an actual hyphenated word. Language names for text elements come from ISO 639, with optional country subcodes from ISO 3166.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct TextElementData {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub underline: Option<NumberOfLines>,
+	pub overline: Option<NumberOfLines>,
+	#[serde(rename = "line-through")]
+	pub line_through: Option<NumberOfLines>,
+	pub rotation: Option<RotationDegrees>,
+	#[serde(rename = "letter-spacing")]
+	pub letter_spacing: Option<NumberOrNormal>,
+	#[serde(rename = "xml:lang")]
+	pub lang: Option<String>,
+	pub dir: Option<TextDirection>,
+}
+
+/// The elision type represents an elision between lyric syllables. The text content specifies the symbol used to display the elision. Common values are a no-break space (Unicode 00A0), an underscore (Unicode 005F), or an undertie (Unicode 203F). If the text content is empty, the smufl attribute is used to specify the symbol to use. If neither text content nor a smufl attribute are present, the elision glyph is application-specific.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Elision {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	pub smufl: Option<SmuflGlyphName>,
+}
+
+/// The extend type represents lyric word extension / melisma lines as well as figured bass extensions. The optional type and position attributes are added in Version 3.0 to provide better formatting control.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Extend {
+	#[serde(rename = "type")]
+	pub extend_type: Option<StartStopContinue>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	pub color: Option<Color>,
+}
+
+/// One element within a lyric.
+///
+/// The laughing element represents a laughing voice. The humming element represents a humming voice. The end-line element comes from RP-017 for Standard MIDI File Lyric meta-events. It facilitates lyric display for Karaoke and similar applications. The end-paragraph element comes from RP-017 for Standard MIDI File Lyric meta-events. It facilitates lyric display for Karaoke and similar applications.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum LyricContent {
+	Syllabic(Syllabic),
+	Text(TextElementData),
+	Elision(Elision),
+	Extend(Extend),
+	Laughing(Empty),
+	Humming(Empty),
+	EndLine(Empty),
+	EndParagraph(Empty),
+}
+
+/// The lyric type represents text underlays for lyrics. Two text elements that are not separated by an elision element are part of the same syllable, but may have different text formatting. The MusicXML XSD is more strict than the DTD in enforcing this by disallowing a second syllabic element unless preceded by an elision element. The lyric number indicates multiple lines, though a name can be used as well. Common name examples are verse and chorus.
+///
+/// Justification is center by default; placement is below by default. Vertical alignment is to the baseline of the text and horizontal alignment matches justification. The print-object attribute can override a note's print-lyric attribute in cases where only some lyrics on a note are printed, as when lyrics for later verses are printed in a block of text rather than with each note. The time-only attribute precisely specifies which lyrics are to be sung which time through a repeated section.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Lyric {
+	#[serde(rename = "$value", default)]
+	pub content: Vec<LyricContent>,
+	pub number: Option<String>,
+	pub name: Option<String>,
+	pub justify: Option<LeftCenterRight>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	pub placement: Option<AboveBelow>,
+	pub color: Option<Color>,
+	#[serde(rename = "print-object")]
+	pub print_object: Option<YesNo>,
+	#[serde(rename = "time-only")]
+	pub time_only: Option<TimeOnly>,
+	pub id: Option<String>,
+}
+
+/// The text of a verse of lyrics assembled from the lyrics of consecutive notes, with the position of the note each syllable starts on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LyricVerse<P> {
+	pub text: String,
+	/// The byte offset in the text where each syllable starts, paired with the position of its note, in order.
+	pub anchors: Vec<(usize, P)>,
+}
+
+impl Lyric {
+	/// Returns the lyric number, which is "1" if not specified.
+	pub fn number(&self) -> &str {
+		self.number.as_deref().unwrap_or("1")
+	}
+
+	/// Returns whether the lyric continues a melisma without starting a new syllable: it has an extend element and no text.
+	pub fn is_melisma(&self) -> bool {
+		self.content.iter().any(|content| matches!(content, LyricContent::Extend(_)))
+			&& !self.content.iter().any(|content| matches!(content, LyricContent::Text(_)))
+	}
+
+	/// Assembles the lyrics with the given number from the notes of a part into the text of a verse. Lyrics are supplied in note order with the note's position, such as a measure number and offset. Syllables that begin or continue a word are joined to the next syllable without a space, elided syllables on one note are separated by a space, notes that only extend a melisma add no text, and end-line and end-paragraph elements add line breaks.
+	pub fn verse<'a, P, I>(lyrics: I, number: &str) -> LyricVerse<P>
+	where
+		P: Clone,
+		I: IntoIterator<Item = (P, &'a Lyric)>,
+	{
+		let mut verse = LyricVerse {
+			text: String::new(),
+			anchors: Vec::new(),
+		};
+		let mut joined = false;
+		for (position, lyric) in lyrics {
+			if lyric.number() != number {
+				continue;
+			}
+			let mut anchored = false;
+			let mut syllabic = None;
+			for content in &lyric.content {
+				match content {
+					LyricContent::Syllabic(value) => syllabic = Some(*value),
+					LyricContent::Text(text) => {
+						if !anchored {
+							if !joined && !verse.text.is_empty() && !verse.text.ends_with('\n') {
+								verse.text.push(' ');
+							}
+							verse.anchors.push((verse.text.len(), position.clone()));
+							anchored = true;
+						}
+						verse.text.push_str(&text.value);
+						joined = matches!(syllabic, Some(Syllabic::Begin) | Some(Syllabic::Middle));
+					},
+					LyricContent::Elision(_) => verse.text.push(' '),
+					LyricContent::EndLine(_) => verse.text.push('\n'),
+					LyricContent::EndParagraph(_) => verse.text.push_str("\n\n"),
+					LyricContent::Extend(_) | LyricContent::Laughing(_) | LyricContent::Humming(_) => {},
+				}
+			}
+		}
+		verse
+	}
+}
+
+/// The first-fret type indicates which fret is shown in the top space of the frame; it is fret 1 if the element is not present. The optional text attribute indicates how this is represented in the fret diagram, while the location attribute indicates whether the text appears to the left or right of the frame.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct FirstFret {
+	#[serde(rename = "$value")]
+	pub value: u64,
+	pub text: Option<String>,
+	pub location: Option<LeftRight>,
+}
+
+/// The barre element indicates placing a finger over multiple strings on a single fret. The type is "start" for the lowest pitched string (e.g., the string with the highest MusicXML number) and is "stop" for the highest pitched string.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Barre {
+	#[serde(rename = "type")]
+	pub barre_type: StartStop,
+	pub color: Option<Color>,
+}
+
+/// The frame-note type represents each note included in the frame. An open string will have a fret value of 0, while a muted string will not be associated with a frame-note element.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct FrameNote {
+	pub string: StringElement,
+	pub fret: Fret,
+	pub fingering: Option<Fingering>,
+	pub barre: Option<Barre>,
+}
+
+/// The frame type represents a frame or fretboard diagram used together with a chord symbol. The representation is based on the NIFF guitar grid with additional information. The frame type's unplayed attribute indicates what to display above a string that has no associated frame-note element. Typical values are x and the empty string. If the attribute is not present, the display of the unplayed string is application-defined.
+///
+/// The frame-strings element gives the overall size of the frame in vertical lines (strings). The frame-frets element gives the overall size of the frame in horizontal spaces (frets).
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Frame {
+	#[serde(rename = "frame-strings")]
+	pub frame_strings: u64,
+	#[serde(rename = "frame-frets")]
+	pub frame_frets: u64,
+	#[serde(rename = "first-fret")]
+	pub first_fret: Option<FirstFret>,
+	#[serde(rename = "frame-note")]
+	pub frame_note: Vec<FrameNote>,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	pub color: Option<Color>,
+	pub halign: Option<LeftCenterRight>,
+	pub valign: Option<ValignImage>,
+	pub height: Option<Tenths>,
+	pub width: Option<Tenths>,
+	pub unplayed: Option<String>,
+	pub id: Option<String>,
+}
+
+/// A barre in a chord shape, covering the strings from `from_string` down to `to_string` on one fret. Strings are numbered as in MusicXML, from 1 for the highest pitched string.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChordBarre {
+	pub fret: u64,
+	pub from_string: StringNumber,
+	pub to_string: StringNumber,
+}
+
+/// A chord diagram normalized for rendering. The frets and fingers are listed per string from the lowest pitched string to the highest, in the order they are drawn from left to right. A fret of None is an unplayed string and 0 is an open string.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChordShape {
+	pub first_fret: u64,
+	pub fret_count: u64,
+	pub frets: Vec<Option<u64>>,
+	pub fingers: Vec<Option<String>>,
+	pub barres: Vec<ChordBarre>,
+}
+
+impl Frame {
+	/// Returns the fret shown in the top space of the frame, which is 1 if not specified.
+	pub fn first_fret(&self) -> u64 {
+		self.first_fret.as_ref().map_or(1, |first_fret| first_fret.value)
+	}
+
+	/// Returns the frame as a normalized chord shape. Frame notes on strings outside the frame are ignored. A barre start without a matching stop extends to the highest pitched string. Returns None if the frame has too many strings to list.
+	pub fn chord_shape(&self) -> Option<ChordShape> {
+		if self.frame_strings > MAX_EXPANSION as u64 {
+			return None;
+		}
+		let strings = self.frame_strings as usize;
+		let mut frets = vec![None; strings];
+		let mut fingers = vec![None; strings];
+		let mut barres = Vec::new();
+		let mut open_barre: Option<(u64, StringNumber)> = None;
+		for frame_note in &self.frame_note {
+			let string = frame_note.string.value;
+			if string == 0 || string > self.frame_strings {
+				continue;
+			}
+			let index = (self.frame_strings - string) as usize;
+			frets[index] = Some(frame_note.fret.value);
+			fingers[index] = frame_note.fingering.as_ref().map(|fingering| fingering.value.clone());
+			match frame_note.barre.as_ref().map(|barre| barre.barre_type) {
+				Some(StartStop::Start) => open_barre = Some((frame_note.fret.value, string)),
+				Some(StartStop::Stop) => {
+					if let Some((fret, from_string)) = open_barre.take() {
+						barres.push(ChordBarre {
+							fret,
+							from_string,
+							to_string: string,
+						});
+					}
+				},
+				None => {},
+			}
+		}
+		if let Some((fret, from_string)) = open_barre {
+			barres.push(ChordBarre {
+				fret,
+				from_string,
+				to_string: 1,
+			});
+		}
+		Some(ChordShape {
+			first_fret: self.first_fret(),
+			fret_count: self.frame_frets,
+			frets,
+			fingers,
+			barres,
+		})
+	}
+}
+
+/// The midi-device type corresponds to the DeviceName meta event in Standard MIDI Files. The optional port attribute is a number from 1 to 16 that can be used with the unofficial MIDI 1.0 port (or cable) meta event. Unlike the DeviceName meta event, there can be multiple midi-device elements per MusicXML part. The optional id attribute refers to the score-instrument assigned to this device. If missing, the device assignment affects all score-instrument elements in the score-part.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct MidiDevice {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	pub port: Option<Midi16>,
+	pub id: Option<String>,
+}
+
+/// The midi-instrument type defines MIDI 1.0 instrument playback. The midi-instrument element can be a part of either the score-instrument element at the start of a part, or the sound element within a part. The id attribute refers to the score-instrument affected by the change.
+///
+/// The midi-channel element specifies a MIDI 1.0 channel numbers ranging from 1 to 16. The midi-name element corresponds to a ProgramName meta-event within a Standard MIDI File. The midi-bank element specifies a MIDI 1.0 bank number ranging from 1 to 16,384. The midi-program element specifies a MIDI 1.0 program number ranging from 1 to 128. For unpitched instruments, the midi-unpitched element specifies a MIDI 1.0 note number ranging from 1 to 128. It is usually used with MIDI banks for percussion. Note that MIDI 1.0 note numbers are generally specified from 0 to 127 rather than the 1 to 128 numbering used in this element.
+///
+/// The volume element value is a percentage of the maximum ranging from 0 to 100, with decimal values allowed. This corresponds to a scaling value for the MIDI 1.0 channel volume controller. The pan and elevation elements allow placing of sound in a 3-D space relative to the listener. Both are expressed in degrees ranging from -180 to 180. For pan, 0 is straight ahead, -90 is hard left, 90 is hard right, and -180 and 180 are directly behind the listener. For elevation, 0 is level with the listener, 90 is directly above, and -90 is directly below.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct MidiInstrument {
+	#[serde(rename = "midi-channel")]
+	pub midi_channel: Option<Midi16>,
+	#[serde(rename = "midi-name")]
+	pub midi_name: Option<String>,
+	#[serde(rename = "midi-bank")]
+	pub midi_bank: Option<Midi16384>,
+	#[serde(rename = "midi-program")]
+	pub midi_program: Option<Midi128>,
+	#[serde(rename = "midi-unpitched")]
+	pub midi_unpitched: Option<Midi128>,
+	pub volume: Option<Percent>,
+	pub pan: Option<RotationDegrees>,
+	pub elevation: Option<RotationDegrees>,
+	pub id: String,
+}
+
+impl MidiInstrument {
+	/// Returns the MIDI 1.0 channel volume controller value for the volume, if specified.
+	pub fn volume_controller(&self) -> Option<u8> {
+		self.volume.map(|volume| (u32::from(volume.min(100)) * 127 / 100) as u8)
+	}
+
+	/// Returns the MIDI 1.0 pan controller value for the pan angle, if specified. Angles outside -180 to 180 are clamped to that range, and angles behind the listener are folded to the front, so -90 is hard left, 0 is center, and 90 is hard right.
+	pub fn pan_controller(&self) -> Option<u8> {
+		self.pan.map(|pan| {
+			let front = match pan.clamp(-180, 180) {
+				pan if pan < -90 => -180 - pan,
+				pan if pan > 90 => 180 - pan,
+				pan => pan,
+			};
+			((i32::from(front) + 90) * 127 / 180) as u8
+		})
+	}
+}
+
+/// The score-instrument type represents a single instrument within a score-part. As with the score-part type, each score-instrument has a required ID attribute, a name, and an optional abbreviation.
+///
+/// A score-instrument type is also required if the score specifies MIDI 1.0 channels, banks, or programs. An initial midi-instrument assignment can also be made here. MusicXML software should be able to automatically assign reasonable channels and instruments without these elements in simple cases, such as where part names match General MIDI instrument names.
+///
+/// The instrument-name element is typically used within a software application, rather than appearing on the printed page of a score. The optional instrument-abbreviation element is typically used within a software application, rather than appearing on the printed page of a score. The instrument-sound, solo, ensemble, and virtual-instrument elements are the same as in instrument-change.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ScoreInstrument {
+	#[serde(rename = "instrument-name")]
+	pub instrument_name: String,
+	#[serde(rename = "instrument-abbreviation")]
+	pub instrument_abbreviation: Option<String>,
+	#[serde(rename = "instrument-sound")]
+	pub instrument_sound: Option<String>,
+	pub solo: Option<Empty>,
+	pub ensemble: Option<PositiveIntegerOrEmpty>,
+	#[serde(rename = "virtual-instrument")]
+	pub virtual_instrument: Option<VirtualInstrument>,
+	pub id: String,
+}
+
+/// The part-name type describes the name or abbreviation of a score-part element. Formatting attributes for the part-name element are deprecated in Version 2.0 in favor of the new part-name-display and part-abbreviation-display elements.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PartName {
+	#[serde(rename = "$value", default)]
+	pub value: String,
+	#[serde(rename = "default-x")]
+	pub default_x: Option<Tenths>,
+	#[serde(rename = "default-y")]
+	pub default_y: Option<Tenths>,
+	#[serde(rename = "relative-x")]
+	pub relative_x: Option<Tenths>,
+	#[serde(rename = "relative-y")]
+	pub relative_y: Option<Tenths>,
+	#[serde(rename = "font-family")]
+	pub font_family: Option<CommaSeparatedText>,
+	#[serde(rename = "font-style")]
+	pub font_style: Option<FontStyle>,
+	#[serde(rename = "font-size")]
+	pub font_size: Option<FontSize>,
+	#[serde(rename = "font-weight")]
+	pub font_weight: Option<FontWeight>,
+	pub color: Option<Color>,
+	#[serde(rename = "print-object")]
+	pub print_object: Option<YesNo>,
+	pub justify: Option<LeftCenterRight>,
+}
+
+/// The score-part type collects part-wide information for each part in a score. Often, each MusicXML part corresponds to a track in a Standard MIDI Format 1 file. In this case, the midi-device element is used to make a MIDI device or port assignment for the given track. Initial midi-instrument assignments may be made here as well. The group element allows part groupings within a score-part. The player elements, new in Version 4.0, allow multiple players to share the same part.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ScorePart {
+	#[serde(rename = "part-name")]
+	pub part_name: PartName,
+	#[serde(rename = "part-abbreviation")]
+	pub part_abbreviation: Option<PartName>,
+	#[serde(default)]
+	pub group: Vec<String>,
+	#[serde(rename = "score-instrument", default)]
+	pub score_instrument: Vec<ScoreInstrument>,
+	#[serde(default)]
+	pub player: Vec<Player>,
+	#[serde(rename = "midi-device", default)]
+	pub midi_device: Vec<MidiDevice>,
+	#[serde(rename = "midi-instrument", default)]
+	pub midi_instrument: Vec<MidiInstrument>,
+	pub id: String,
+}
+
+impl ScorePart {
+	/// Returns the score-instrument with the given id, as referenced by a note's instrument element.
+	pub fn score_instrument(&self, instrument: &str) -> Option<&ScoreInstrument> {
+		self.score_instrument.iter().find(|score_instrument| score_instrument.id == instrument)
+	}
+
+	/// Returns the MIDI instrument assignment for the given score-instrument, if any.
+	pub fn midi_instrument(&self, instrument: &str) -> Option<&MidiInstrument> {
+		self.midi_instrument.iter().find(|midi_instrument| midi_instrument.id == instrument)
+	}
+
+	/// Returns the MIDI program of the part's first instrument that specifies one.
+	pub fn midi_program(&self) -> Option<Midi128> {
+		self.midi_instrument.iter().find_map(|midi_instrument| midi_instrument.midi_program)
+	}
+
+	/// Returns the MIDI device assigned to the given score-instrument, falling back to a device assigned to the whole part.
+	pub fn midi_device(&self, instrument: &str) -> Option<&MidiDevice> {
+		self.midi_device
+			.iter()
+			.find(|device| device.id.as_deref() == Some(instrument))
+			.or_else(|| self.midi_device.iter().find(|device| device.id.is_none()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn trill_realization() {
+		let notes = TrillSound::default().realize().unwrap();
+		assert_eq!(
+			notes,
+			vec![(0.0, OrnamentNote::Upper), (0.25, OrnamentNote::Main), (0.5, OrnamentNote::Upper), (0.75, OrnamentNote::Main)]
+		);
+		let trill = TrillSound {
+			start_note: StartNote::Below,
+			two_note_turn: TwoNoteTurn::Half,
+			..TrillSound::default()
+		};
+		let notes: Vec<OrnamentNote> = trill.realize().unwrap().into_iter().map(|(_, note)| note).collect();
+		assert_eq!(
+			notes,
+			vec![OrnamentNote::Lower, OrnamentNote::Main, OrnamentNote::Upper, OrnamentNote::Main, OrnamentNote::Lower, OrnamentNote::Main]
+		);
+		assert_eq!(trill.turn_interval(), Some(1.0));
+		let endless = TrillSound {
+			beats: 1e12,
+			..TrillSound::default()
+		};
+		assert_eq!(endless.realize(), None);
+	}
+
+	#[test]
+	fn trill_sound_defaults() {
+		let defaults = TrillSound {
+			trill_step: TrillStep::Half,
+			..TrillSound::default()
+		};
+		let trill: EmptyTrillSound = quick_xml::de::from_str(r#"<trill-mark start-note="main" beats="6"/>"#).unwrap();
+		let sound = trill.trill_sound(&defaults);
+		assert_eq!(sound.start_note, StartNote::Main);
+		assert_eq!(sound.trill_step, TrillStep::Half);
+		assert_eq!(sound.beats, 6.0);
+		assert_eq!(sound.second_beat, 25);
+	}
+
+	#[test]
+	fn id_index() {
+		let bookmarks = ["intro", "coda", "intro"];
+		let index = IdIndex::new(bookmarks.iter().copied());
+		assert_eq!(index.resolve("coda"), Some(1));
+		assert_eq!(index.resolve("intro"), Some(0));
+		assert_eq!(index.duplicates(), ["intro".to_string()]);
+		let link = |href: &str| Link {
+			href: href.to_string(),
+			link_type: None,
+			role: None,
+			title: None,
+			show: None,
+			actuate: None,
+			name: None,
+			element: None,
+			position: None,
+			default_x: None,
+			default_y: None,
+			relative_x: None,
+			relative_y: None,
+		};
+		let links = [link("#coda"), link("#fine"), link("http://www.musicxml.com/")];
+		assert_eq!(index.dangling(links.iter()), vec![&links[1]]);
+	}
+
+	#[test]
+	fn key_accidentals() {
+		let a_major = Key::from_tonic(Step::A, 0.0, Mode::Major).unwrap();
+		assert_eq!(a_major.fifths, 3);
+		assert_eq!(a_major.accidental_for(Step::F), 1.0);
+		assert_eq!(a_major.accidental_for(Step::G), 1.0);
+		assert_eq!(a_major.accidental_for(Step::D), 0.0);
+		let b_flat_major = Key::from_tonic(Step::B, -1.0, Mode::Major).unwrap();
+		assert_eq!(b_flat_major.accidental_for(Step::E), -1.0);
+		assert_eq!(b_flat_major.accidental_for(Step::A), 0.0);
+		let nine_sharps = Key::from_tonic(Step::D, 1.0, Mode::Major).unwrap();
+		assert_eq!(nine_sharps.fifths, 9);
+		assert_eq!(nine_sharps.accidental_for(Step::F), 2.0);
+		assert_eq!(nine_sharps.accidental_for(Step::G), 1.0);
+		assert_eq!(Key::from_tonic(Step::F, -1.0, Mode::Major).unwrap().accidental_for(Step::B), -2.0);
+		assert_eq!(Key::from_tonic(Step::C, 0.5, Mode::Major), None);
+	}
+
+	#[test]
+	fn key_tonic() {
+		let c_major = Key::from_tonic(Step::C, 0.0, Mode::Major).unwrap();
+		assert_eq!(c_major.tonic(&Mode::Major), Some((Step::C, 0.0)));
+		assert_eq!(c_major.tonic(&Mode::Dorian), Some((Step::D, 0.0)));
+		assert_eq!(c_major.tonic(&Mode::None), None);
+		assert_eq!(Key::from_tonic(Step::E, -1.0, Mode::Major).unwrap().tonic(&Mode::Major), Some((Step::E, -1.0)));
+		assert_eq!(Key::from_tonic(Step::A, 0.0, Mode::Major).unwrap().tonic(&Mode::Minor), Some((Step::F, 1.0)));
+		assert_eq!(Key::from_tonic(Step::C, -1.0, Mode::Major).unwrap().fifths, -7);
+		assert_eq!(Key::from_tonic(Step::E, -1.0, Mode::Minor).map(|key| key.fifths), Some(-6));
+	}
+
+	fn signature(beats: &str, beat_type: &str) -> TimeSignature {
+		TimeSignature {
+			beats: beats.to_string(),
+			beat_type: beat_type.to_string(),
+		}
+	}
+
+	#[test]
+	fn time_deserialize() {
+		let time: Time = quick_xml::de::from_str(
+			"<time><beats>2</beats><beat-type>4</beat-type><beats>3</beats><beat-type>8</beat-type></time>",
+		)
+		.unwrap();
+		assert_eq!(time.signatures(), [signature("2", "4"), signature("3", "8")]);
+		assert_eq!(time.measure_duration(), Some(Duration::new(7, 8)));
+		assert!(!time.is_compound());
+
+		let time: Time = quick_xml::de::from_str(
+			r#"<time symbol="normal"><beats>6</beats><beat-type>8</beat-type><interchangeable><time-relation>parentheses</time-relation><beats>2</beats><beat-type>4</beat-type></interchangeable></time>"#,
+		)
+		.unwrap();
+		assert_eq!(time.symbol, Some(TimeSymbol::Normal));
+		assert!(time.is_compound());
+		assert_eq!(
+			time.content,
+			TimeContent::Signatures {
+				signatures: vec![signature("6", "8")],
+				interchangeable: Some(Interchangeable {
+					time_relation: Some(TimeRelation::Parentheses),
+					signatures: vec![signature("2", "4")],
+					symbol: None,
+					separator: None,
+				}),
+			}
+		);
+
+		let time: Time = quick_xml::de::from_str("<time><senza-misura>X</senza-misura></time>").unwrap();
+		assert_eq!(time.content, TimeContent::SenzaMisura("X".to_string()));
+		assert_eq!(time.measure_duration(), None);
+
+		assert!(quick_xml::de::from_str::<Time>("<time><beats>3</beats></time>").is_err());
+		assert!(quick_xml::de::from_str::<Time>("<time><beat-type>4</beat-type><beats>3</beats></time>").is_err());
+	}
+
+	#[test]
+	fn time_beats() {
+		assert_eq!(signature("3+2", "8").beat_groups(), Some(vec![3, 2]));
+		assert_eq!(signature("6", "8").beat_groups(), Some(vec![3, 3]));
+		assert_eq!(signature("3", "4").beat_groups(), Some(vec![1, 1, 1]));
+		assert_eq!(signature("3+2", "8").measure_duration(), Some(Duration::new(5, 8)));
+		assert!(!signature("3+3", "8").is_compound());
+		assert_eq!(signature("4", "0").measure_duration(), None);
+		let huge = signature("4000000000", "4");
+		assert_eq!(huge.beat_count(), Some(4_000_000_000));
+		assert_eq!(huge.beat_groups(), None);
+		assert_eq!(huge.measure_duration(), Some(Duration::new(1_000_000_000, 1)));
+		assert_eq!(signature("4000000000+4000000000", "4").beat_count(), None);
+
+		let time: Time = quick_xml::de::from_str("<time><beats>3+2</beats><beat-type>8</beat-type></time>").unwrap();
+		assert_eq!(time.beat_positions(), vec![Duration::ZERO, Duration::new(3, 8)]);
+		assert_eq!(time.measure_length(2), Some(5));
+		let time: Time = quick_xml::de::from_str("<time><beats>4000000002</beats><beat-type>8</beat-type></time>").unwrap();
+		assert!(time.is_compound());
+		assert_eq!(time.beat_positions(), Vec::new());
+	}
+
+	#[test]
+	fn clef_staff_position() {
+		let pitch = |step, octave| Pitch { step, alter: None, octave };
+		let treble: Clef = quick_xml::de::from_str("<clef><sign>G</sign><line>2</line></clef>").unwrap();
+		assert_eq!(treble.staff_position(&pitch(Step::E, 4)), Some(0));
+		assert_eq!(treble.staff_position(&pitch(Step::F, 5)), Some(8));
+		assert_eq!(treble.staff_position(&pitch(Step::C, 4)), Some(-2));
+		let bass: Clef = quick_xml::de::from_str("<clef><sign>F</sign></clef>").unwrap();
+		assert_eq!(bass.staff_line(), Some(4));
+		assert_eq!(bass.staff_position(&pitch(Step::G, 2)), Some(0));
+		let tenor: Clef = quick_xml::de::from_str("<clef><sign>G</sign><clef-octave-change>-1</clef-octave-change></clef>").unwrap();
+		assert_eq!(tenor.staff_position(&pitch(Step::E, 3)), Some(0));
+		let percussion: Clef = quick_xml::de::from_str("<clef><sign>percussion</sign></clef>").unwrap();
+		assert_eq!(percussion.staff_line(), None);
+		assert_eq!(percussion.staff_position(&pitch(Step::E, 4)), None);
+	}
+
+	fn names(pitches: &[Pitch]) -> Vec<(Step, Option<Semitones>, Octave)> {
+		pitches.iter().map(|pitch| (pitch.step, pitch.alter, pitch.octave)).collect()
+	}
+
+	#[test]
+	fn harmony_pitches() {
+		let c_major = Key::from_fifths(0, Some(Mode::Major));
+		let harmony: Harmony = quick_xml::de::from_str(
+			"<harmony><root><root-step>D</root-step></root><kind>minor-seventh</kind><bass><bass-step>C</bass-step></bass></harmony>",
+		)
+		.unwrap();
+		assert_eq!(
+			names(&harmony.pitches(&c_major, 4)),
+			vec![(Step::C, None, 3), (Step::D, None, 4), (Step::F, None, 4), (Step::A, None, 4), (Step::C, None, 5)]
+		);
+		let harmony: Harmony = quick_xml::de::from_str(
+			"<harmony><root><root-step>G</root-step></root><kind>dominant</kind><inversion>1</inversion><degree><degree-value>9</degree-value><degree-alter>-1</degree-alter><degree-type>add</degree-type></degree></harmony>",
+		)
+		.unwrap();
+		assert_eq!(
+			names(&harmony.pitches(&c_major, 3)),
+			vec![(Step::B, None, 3), (Step::D, None, 4), (Step::F, None, 4), (Step::A, Some(-1.0), 4), (Step::G, None, 4)]
+		);
+		let harmony: Harmony = quick_xml::de::from_str(
+			"<harmony><numeral><numeral-root>5</numeral-root></numeral><kind>dominant</kind><inversion>1</inversion></harmony>",
+		)
+		.unwrap();
+		assert_eq!(
+			names(&harmony.pitches(&Key::from_fifths(-1, Some(Mode::Major)), 3)),
+			vec![(Step::E, None, 3), (Step::G, None, 3), (Step::B, Some(-1.0), 3), (Step::C, None, 4)]
+		);
+		let harmony: Harmony = quick_xml::de::from_str("<harmony><kind>none</kind></harmony>").unwrap();
+		assert!(harmony.pitches(&c_major, 4).is_empty());
+	}
+
+	#[test]
+	fn numeral_root() {
+		let numeral: Numeral = quick_xml::de::from_str("<numeral><numeral-root>7</numeral-root></numeral>").unwrap();
+		let a_minor = Key::from_fifths(0, Some(Mode::Minor));
+		assert_eq!(numeral.key(&a_minor), (0, NumeralMode::Minor));
+		assert_eq!(numeral.root(&a_minor), Some((Step::G, 0.0)));
+		let numeral: Numeral = quick_xml::de::from_str(
+			"<numeral><numeral-root>7</numeral-root><numeral-key><numeral-fifths>0</numeral-fifths><numeral-mode>harmonic minor</numeral-mode></numeral-key></numeral>",
+		)
+		.unwrap();
+		assert_eq!(numeral.root(&a_minor), Some((Step::G, 1.0)));
+		assert_eq!(Numeral::degree_of(Step::G, 1.0, 0, NumeralMode::HarmonicMinor), (7, 0.0));
+		assert_eq!(Numeral::degree_of(Step::B, -1.0, -1, NumeralMode::Major), (4, 0.0));
+		let numeral: Numeral = quick_xml::de::from_str("<numeral><numeral-root>8</numeral-root></numeral>").unwrap();
+		assert_eq!(numeral.root(&a_minor), None);
+	}
+
+	#[test]
+	fn listen_deserialize() {
+		let listen: Listen = quick_xml::de::from_str(
+			r#"<listen><assess type="no" player="P1-I1"/><wait/><other-listen type="cue">solo</other-listen></listen>"#,
+		)
+		.unwrap();
+		assert_eq!(
+			listen.content,
+			vec![
+				ListenContent::Assess(Assess {
+					assess_type: YesNo::No,
+					player: Some("P1-I1".to_string()),
+					time_only: None,
+				}),
+				ListenContent::Wait(Wait { player: None, time_only: None }),
+				ListenContent::OtherListen(OtherListening {
+					value: "solo".to_string(),
+					listening_type: "cue".to_string(),
+					player: None,
+					time_only: None,
+				}),
+			]
+		);
+	}
+
+	#[test]
+	fn listening_deserialize() {
+		let listening: Listening = quick_xml::de::from_str(
+			r#"<listening><sync type="mostly-tempo" latency="20"/><offset sound="yes">-2</offset><sync type="event" player="P2"/></listening>"#,
+		)
+		.unwrap();
+		assert_eq!(
+			listening.content,
+			vec![
+				ListeningContent::Sync(Sync {
+					sync_type: SyncType::MostlyTempo,
+					latency: Some(20),
+					player: None,
+					time_only: None,
+				}),
+				ListeningContent::Sync(Sync {
+					sync_type: SyncType::Event,
+					latency: None,
+					player: Some("P2".to_string()),
+					time_only: None,
+				}),
+			]
+		);
+		assert_eq!(listening.offset, Some(Offset { value: -2.0, sound: Some(YesNo::Yes) }));
+	}
+
+	#[test]
+	fn percussion_smufl() {
+		let percussion: Percussion = quick_xml::de::from_str(
+			r#"<percussion smufl="pictGlass"><glass smufl="pictWindChimesGlass">wind chimes</glass></percussion>"#,
+		)
+		.unwrap();
+		assert_eq!(percussion.smufl.as_ref().map(SmuflGlyphName::as_str), Some("pictGlass"));
+		assert_eq!(
+			percussion.pictogram,
+			PercussionPictogram::Glass(Glass {
+				value: GlassValue::WindChimes,
+				smufl: "pictWindChimesGlass".parse().ok(),
+			})
+		);
+		let percussion: Percussion = quick_xml::de::from_str(r#"<percussion><timpani smufl="pictTimpani"/></percussion>"#).unwrap();
+		assert_eq!(percussion.pictogram, PercussionPictogram::Timpani(Timpani { smufl: "pictTimpani".parse().ok() }));
+	}
+
+	#[test]
+	fn dynamics_velocity() {
+		let dynamics: Dynamics = quick_xml::de::from_str("<dynamics><sf/><mp/></dynamics>").unwrap();
+		assert_eq!(dynamics.marks, vec![DynamicsValue::Sf, DynamicsValue::Mp]);
+		assert_eq!(dynamics.velocity(), Some(112));
+		let dynamics: Dynamics = quick_xml::de::from_str("<dynamics><other-dynamics>sfmp</other-dynamics><pp/></dynamics>").unwrap();
+		assert_eq!(dynamics.marks[0].velocity(), None);
+		assert_eq!(dynamics.velocity(), Some(33));
+		assert_eq!(DynamicsValue::N.velocity(), Some(0));
+		assert_eq!(DynamicsValue::Ffffff.velocity(), Some(127));
+	}
+
+	#[test]
+	fn metronome_bpm() {
+		let metronome: Metronome = quick_xml::de::from_str(
+			r#"<metronome parentheses="yes"><beat-unit>quarter</beat-unit><beat-unit-dot/><per-minute>c. 80</per-minute></metronome>"#,
+		)
+		.unwrap();
+		assert_eq!(metronome.parentheses, Some(YesNo::Yes));
+		assert_eq!(metronome.bpm_for(NoteTypeValue::Quarter), Some(120.0));
+		assert_eq!(metronome.bpm_for(NoteTypeValue::Eighth), Some(240.0));
+
+		let metronome: Metronome = quick_xml::de::from_str(
+			"<metronome><beat-unit>half</beat-unit><beat-unit-tied><beat-unit>quarter</beat-unit></beat-unit-tied><per-minute>40</per-minute></metronome>",
+		)
+		.unwrap();
+		assert_eq!(metronome.bpm_for(NoteTypeValue::Quarter), Some(120.0));
+
+		let dotted = BeatUnitGroup {
+			beat_unit: NoteTypeValue::Quarter,
+			beat_unit_dots: 64,
+			beat_unit_tied: Vec::new(),
+		};
+		assert_eq!(dotted.duration(), None);
+	}
+
+	#[test]
+	fn metronome_deserialize() {
+		let metronome: Metronome = quick_xml::de::from_str(
+			"<metronome><beat-unit>quarter</beat-unit><beat-unit>half</beat-unit><beat-unit-dot/></metronome>",
+		)
+		.unwrap();
+		assert_eq!(
+			metronome.content,
+			MetronomeContent::BeatUnit {
+				beat_unit: BeatUnitGroup {
+					beat_unit: NoteTypeValue::Quarter,
+					beat_unit_dots: 0,
+					beat_unit_tied: Vec::new(),
+				},
+				tempo: MetronomeTempo::BeatUnit(BeatUnitGroup {
+					beat_unit: NoteTypeValue::Half,
+					beat_unit_dots: 1,
+					beat_unit_tied: Vec::new(),
+				}),
+			}
+		);
+		assert_eq!(metronome.bpm_for(NoteTypeValue::Quarter), None);
+
+		let metronome: Metronome = quick_xml::de::from_str(
+			"<metronome><metronome-arrows/><metronome-note><metronome-type>eighth</metronome-type></metronome-note><metronome-note><metronome-type>eighth</metronome-type></metronome-note><metronome-relation>equals</metronome-relation><metronome-note><metronome-type>quarter</metronome-type></metronome-note></metronome>",
+		)
+		.unwrap();
+		match metronome.content {
+			MetronomeContent::Notes {
+				metronome_arrows,
+				notes,
+				metronome_relation,
+				related_notes,
+			} => {
+				assert!(metronome_arrows);
+				assert_eq!(notes.len(), 2);
+				assert_eq!(metronome_relation.as_deref(), Some("equals"));
+				assert_eq!(related_notes.len(), 1);
+				assert_eq!(related_notes[0].metronome_type, NoteTypeValue::Quarter);
+			}
+			content => panic!("unexpected metronome content {:?}", content),
+		}
+
+		assert!(quick_xml::de::from_str::<Metronome>("<metronome><beat-unit>quarter</beat-unit></metronome>").is_err());
+	}
+
+	#[test]
+	fn pedal_spans() {
+		let pedals: Vec<Pedal> = [
+			r#"<pedal type="start" line="yes"/>"#,
+			r#"<pedal type="sostenuto" number="2"/>"#,
+			r#"<pedal type="change"/>"#,
+			r#"<pedal type="continue"/>"#,
+			r#"<pedal type="stop" number="2"/>"#,
+			r#"<pedal type="stop"/>"#,
+			r#"<pedal type="start"/>"#,
+		]
+		.iter()
+		.map(|xml| quick_xml::de::from_str(xml).unwrap())
+		.collect();
+		assert_eq!(pedals[0].sign(), YesNo::No);
+		assert_eq!(pedals[1].sign(), YesNo::Yes);
+		let spans = Pedal::spans((1..).zip(pedals.iter()));
+		assert_eq!(
+			spans,
+			vec![
+				PedalSpan { number: 1, sostenuto: false, start: 1, stop: Some(3) },
+				PedalSpan { number: 2, sostenuto: true, start: 2, stop: Some(5) },
+				PedalSpan { number: 1, sostenuto: false, start: 3, stop: Some(6) },
+				PedalSpan { number: 1, sostenuto: false, start: 7, stop: None },
+			]
+		);
+	}
+
+	#[test]
+	fn arpeggiate_roll() {
+		let up: Arpeggiate = quick_xml::de::from_str("<arpeggiate/>").unwrap();
+		let down: Arpeggiate = quick_xml::de::from_str(r#"<arpeggiate direction="down" number="2"/>"#).unwrap();
+		let pitches = [
+			Pitch { step: Step::G, alter: None, octave: 4 },
+			Pitch { step: Step::C, alter: None, octave: 4 },
+			Pitch { step: Step::E, alter: None, octave: 4 },
+		];
+		let spread = Duration::new(1, 8);
+		assert_eq!(up.rolled_offsets(&pitches, spread), vec![Duration::new(1, 8), Duration::ZERO, Duration::new(1, 16)]);
+		assert_eq!(down.rolled_offsets(&pitches, spread), vec![Duration::ZERO, Duration::new(1, 8), Duration::new(1, 16)]);
+		assert_eq!(up.rolled_offsets(&pitches[..1], spread), vec![Duration::ZERO]);
+		let groups = Arpeggiate::group(vec![("C4", &up), ("C3", &down), ("E4", &up), ("G3", &down)]);
+		assert_eq!(groups, vec![vec!["C4", "E4"], vec!["C3", "G3"]]);
+	}
+
+	#[test]
+	fn glissando_and_slide() {
+		let c4 = Pitch { step: Step::C, alter: None, octave: 4 };
+		let e4 = Pitch { step: Step::E, alter: None, octave: 4 };
+		assert_eq!(Glissando::steps(&c4, &e4), Some(vec![48.0, 49.0, 50.0, 51.0]));
+		assert_eq!(Glissando::steps(&e4, &c4), Some(vec![52.0, 51.0, 50.0, 49.0]));
+		let far = Pitch { step: Step::C, alter: Some(1e12), octave: 4 };
+		assert_eq!(Glissando::steps(&c4, &far), None);
+		let nan = Pitch { step: Step::C, alter: Some(f64::NAN), octave: 4 };
+		assert_eq!(Glissando::steps(&c4, &nan), None);
+
+		let slide: Slide = quick_xml::de::from_str(r#"<slide type="start" beats="3"/>"#).unwrap();
+		assert_eq!(slide.bend_curve(&c4, &e4), Some(vec![(0.25, 0.0), (0.5, 2.0), (0.75, 4.0)]));
+		let slide: Slide = quick_xml::de::from_str(r#"<slide type="start" beats="1e12"/>"#).unwrap();
+		assert_eq!(slide.bend_curve(&c4, &e4), None);
+	}
+
+	#[test]
+	fn tremolo_strokes() {
+		let single: Tremolo = quick_xml::de::from_str(r#"<tremolo type="single">2</tremolo>"#).unwrap();
+		assert_eq!(single.stroke_duration(NoteTypeValue::Quarter), Some(Duration::new(1, 16)));
+		assert_eq!(single.stroke_duration(NoteTypeValue::Eighth), Some(Duration::new(1, 32)));
+		let expanded = single.expand(Duration::new(1, 4), NoteTypeValue::Quarter).unwrap();
+		assert_eq!(expanded, vec![(Duration::new(1, 16), TremoloNote::Start); 4]);
+		assert_eq!(single.expand(Duration::new(3, 32), NoteTypeValue::Quarter), None);
+
+		let start: Tremolo = quick_xml::de::from_str(r#"<tremolo type="start">2</tremolo>"#).unwrap();
+		let notes: Vec<TremoloNote> = start.expand(Duration::new(1, 8), NoteTypeValue::Quarter).unwrap().into_iter().map(|(_, note)| note).collect();
+		assert_eq!(notes, vec![TremoloNote::Start, TremoloNote::Stop, TremoloNote::Start, TremoloNote::Stop]);
+
+		let unmeasured: Tremolo = quick_xml::de::from_str(r#"<tremolo type="unmeasured">0</tremolo>"#).unwrap();
+		assert_eq!(unmeasured.stroke_duration(NoteTypeValue::Quarter), None);
+		let excessive: Tremolo = quick_xml::de::from_str("<tremolo>200</tremolo>").unwrap();
+		assert_eq!(excessive.stroke_duration(NoteTypeValue::Quarter), None);
+		assert_eq!(single.expand(Duration::new(1000, 1), NoteTypeValue::Quarter), None);
+	}
+
+	#[test]
+	fn articulation_flags() {
+		let articulations: Articulations =
+			quick_xml::de::from_str(r#"<articulations><staccato/><strong-accent type="down"/><doit/></articulations>"#).unwrap();
+		let flags = articulations.articulation_flags();
+		assert!(flags.staccato && flags.strong_accent && flags.slide);
+		assert!(!flags.tenuto && !flags.caesura);
+		assert!(flags.is_accented());
+		assert_eq!(flags.duration_factor(), 0.5);
+		match &articulations.content[1] {
+			Articulation::StrongAccent(accent) => assert_eq!(accent.strong_accent_type(), UpDown::Down),
+			articulation => panic!("unexpected articulation {:?}", articulation),
+		}
+		let flags = ArticulationFlags {
+			staccatissimo: true,
+			staccato: true,
+			..ArticulationFlags::default()
+		};
+		assert_eq!(flags.duration_factor(), 0.25);
+		assert!(!ArticulationFlags::default().is_accented());
+	}
+
+	#[test]
+	fn technical() {
+		let technical: Technical = quick_xml::de::from_str(
+			r#"<technical id="t1"><up-bow/><fingering substitution="yes">3</fingering><fret>5</fret><string>2</string><bend shape="curved"><bend-alter>-1.5</bend-alter><release/></bend></technical>"#,
+		)
+		.unwrap();
+		assert_eq!(technical.id.as_deref(), Some("t1"));
+		assert_eq!(technical.content.len(), 5);
+		assert!(matches!(technical.content[0], TechnicalContent::UpBow(_)));
+		match &technical.content[1] {
+			TechnicalContent::Fingering(fingering) => {
+				assert_eq!(fingering.value, "3");
+				assert_eq!(fingering.substitution, Some(YesNo::Yes));
+			}
+			content => panic!("unexpected content {:?}", content),
+		}
+		assert!(matches!(&technical.content[2], TechnicalContent::Fret(fret) if fret.value == 5));
+		assert!(matches!(&technical.content[3], TechnicalContent::String(string) if string.value == 2));
+		match &technical.content[4] {
+			TechnicalContent::Bend(bend) => {
+				assert_eq!(bend.bend_alter, -1.5);
+				assert_eq!(bend.shape, Some(BendShape::Curved));
+				assert!(bend.release.is_some() && bend.pre_bend.is_none());
+			}
+			content => panic!("unexpected content {:?}", content),
+		}
+	}
+
+	#[test]
+	fn fermata_shape() {
+		let fermata: Fermata = quick_xml::de::from_str(r#"<fermata type="inverted">angled</fermata>"#).unwrap();
+		assert_eq!(fermata.shape(), FermataShape::Angled);
+		assert_eq!(fermata.fermata_type(), UprightInverted::Inverted);
+		let fermata: Fermata = quick_xml::de::from_str("<fermata/>").unwrap();
+		assert_eq!(fermata.shape(), FermataShape::Normal);
+		assert_eq!(fermata.fermata_type(), UprightInverted::Upright);
+	}
+
+	#[test]
+	fn staff_config_resolve() {
+		let first: StaffDetails = quick_xml::de::from_str(
+			r#"<staff-details><staff-type>alternate</staff-type><staff-lines>6</staff-lines><staff-tuning line="1"><tuning-step>E</tuning-step><tuning-octave>2</tuning-octave></staff-tuning><staff-tuning line="2"><tuning-step>A</tuning-step><tuning-octave>2</tuning-octave></staff-tuning></staff-details>"#,
+		)
+		.unwrap();
+		let second: StaffDetails = quick_xml::de::from_str(
+			r#"<staff-details><staff-tuning line="1"><tuning-step>D</tuning-step><tuning-octave>2</tuning-octave></staff-tuning><capo>2</capo></staff-details>"#,
+		)
+		.unwrap();
+
+		let config = StaffConfig::resolve(std::slice::from_ref(&first));
+		assert_eq!(config.staff_type, StaffType::Alternate);
+		assert_eq!(config.staff_lines, 6);
+		assert_eq!(config.capo, 0);
+		assert_eq!(config.fretted_semitone(1, 0), Some(28.0));
+		assert_eq!(config.fretted_semitone(2, 3), Some(36.0));
+		assert_eq!(config.fretted_semitone(3, 0), None);
+
+		let config = StaffConfig::resolve(&[first, second]);
+		assert_eq!(config.staff_type, StaffType::Alternate);
+		assert_eq!(config.staff_tuning.len(), 2);
+		assert_eq!(config.capo, 2);
+		// The capo adds to the fret rather than acting as a minimum.
+		assert_eq!(config.fretted_semitone(1, 0), Some(28.0));
+		assert_eq!(config.fretted_semitone(1, 1), Some(29.0));
+		assert_eq!(config.fretted_semitone(2, 3), Some(38.0));
+	}
+
+	#[test]
+	fn part_group_brackets() {
+		let group = |xml: &str| -> PartGroup { quick_xml::de::from_str(xml).unwrap() };
+		let groups = [
+			group(r#"<part-group type="start" number="1"><group-symbol>bracket</group-symbol><group-barline>yes</group-barline></part-group>"#),
+			group(r#"<part-group type="start" number="2"><group-symbol>brace</group-symbol></part-group>"#),
+			group(r#"<part-group type="stop" number="2"/>"#),
+			group(r#"<part-group type="stop" number="1"/>"#),
+			group(r#"<part-group type="start" number="3"/>"#),
+			group(r#"<part-group type="start" number="4"/>"#),
+			group(r#"<part-group type="stop" number="4"/>"#),
+		];
+		let entries = vec![
+			PartListEntry::PartGroup(&groups[0]),
+			PartListEntry::ScorePart,
+			PartListEntry::PartGroup(&groups[1]),
+			PartListEntry::ScorePart,
+			PartListEntry::ScorePart,
+			PartListEntry::PartGroup(&groups[2]),
+			PartListEntry::PartGroup(&groups[3]),
+			PartListEntry::ScorePart,
+			PartListEntry::PartGroup(&groups[4]),
+			PartListEntry::PartGroup(&groups[5]),
+			PartListEntry::PartGroup(&groups[6]),
+			PartListEntry::ScorePart,
+		];
+		let brackets = PartGroup::brackets(entries);
+		let spans: Vec<_> = brackets
+			.iter()
+			.map(|bracket| (bracket.number.as_str(), bracket.symbol, bracket.first_part, bracket.last_part, bracket.depth))
+			.collect();
+		// Group 4 contains no parts and group 3 is never stopped.
+		assert_eq!(
+			spans,
+			vec![
+				("1", GroupSymbolValue::Bracket, 0, 2, 0),
+				("2", GroupSymbolValue::Brace, 1, 2, 1),
+				("3", GroupSymbolValue::None, 4, 4, 0),
+			]
+		);
+		assert_eq!(brackets[0].barline, Some(GroupBarlineValue::Yes));
+		assert_eq!(brackets[1].barline, None);
+	}
+
+	#[test]
+	fn part_symbol_staves() {
+		let symbol: PartSymbol = quick_xml::de::from_str("<part-symbol>brace</part-symbol>").unwrap();
+		assert_eq!(symbol.staves(3), (1, 3));
+		let symbol: PartSymbol = quick_xml::de::from_str(r#"<part-symbol top-staff="2">bracket</part-symbol>"#).unwrap();
+		assert_eq!(symbol.value, GroupSymbolValue::Bracket);
+		assert_eq!(symbol.staves(3), (2, 3));
+	}
+
+	#[test]
+	fn print_layout_defaults() {
+		let defaults: Defaults = quick_xml::de::from_str(
+			r#"<defaults><scaling><millimeters>7</millimeters><tenths>40</tenths></scaling><system-layout><system-margins><left-margin>10</left-margin><right-margin>0</right-margin></system-margins><system-distance>120</system-distance></system-layout><staff-layout><staff-distance>65</staff-distance></staff-layout><staff-layout number="2"><staff-distance>80</staff-distance></staff-layout></defaults>"#,
+		)
+		.unwrap();
+		assert_eq!(defaults.tenths_to_mm(40.0), Some(7.0));
+		assert_eq!(defaults.staff_distance(1), Some(65.0));
+		assert_eq!(defaults.staff_distance(2), Some(80.0));
+		assert_eq!(defaults.staff_distance(3), None);
+
+		let print: Print = quick_xml::de::from_str(
+			r#"<print new-system="yes"><system-layout><system-distance>150</system-distance></system-layout><staff-layout number="2"><staff-distance>90</staff-distance></staff-layout></print>"#,
+		)
+		.unwrap();
+		assert_eq!(print.new_system, Some(YesNo::Yes));
+		let layout = print.system_layout(&defaults).unwrap();
+		assert_eq!(layout.system_distance, Some(150.0));
+		assert_eq!(layout.system_margins, Some(SystemMargins { left_margin: 10.0, right_margin: 0.0 }));
+		assert_eq!(layout.top_system_distance, None);
+		assert_eq!(print.staff_distance(1, &defaults), Some(65.0));
+		assert_eq!(print.staff_distance(2, &defaults), Some(90.0));
+
+		let print: Print = quick_xml::de::from_str("<print/>").unwrap();
+		assert_eq!(print.system_layout(&defaults), defaults.system_layout);
+	}
+
+	#[test]
+	fn harp_pedals_and_scordatura() {
+		let pedals: HarpPedals = quick_xml::de::from_str(
+			"<harp-pedals><pedal-tuning><pedal-step>D</pedal-step><pedal-alter>0</pedal-alter></pedal-tuning><pedal-tuning><pedal-step>C</pedal-step><pedal-alter>-1</pedal-alter></pedal-tuning></harp-pedals>",
+		)
+		.unwrap();
+		assert_eq!(pedals.alter(Step::D), Some(0.0));
+		assert_eq!(pedals.alter(Step::C), Some(-1.0));
+		assert_eq!(pedals.alter(Step::B), None);
+
+		let scordatura: Scordatura = quick_xml::de::from_str(
+			r#"<scordatura><accord string="4"><tuning-step>D</tuning-step><tuning-octave>3</tuning-octave></accord><accord string="1"><tuning-step>F</tuning-step><tuning-alter>1</tuning-alter><tuning-octave>4</tuning-octave></accord></scordatura>"#,
+		)
+		.unwrap();
+		assert_eq!(scordatura.tuning(4), Some(Pitch { step: Step::D, alter: None, octave: 3 }));
+		assert_eq!(scordatura.tuning(1), Some(Pitch { step: Step::F, alter: Some(1.0), octave: 4 }));
+		assert_eq!(scordatura.tuning(2), None);
+	}
+
+	#[test]
+	fn formatted_text() {
+		let words: FormattedTextId = quick_xml::de::from_str("<words>Allegro   con\tbrio</words>").unwrap();
+		assert_eq!(words.text(), "Allegro con brio");
+		assert_eq!(words.lang(), "it");
+		let words: FormattedTextId =
+			quick_xml::de::from_str(r#"<words xml:lang="de" xml:space="preserve">Sehr  langsam</words>"#).unwrap();
+		assert_eq!(words.text(), "Sehr  langsam");
+		assert_eq!(words.lang(), "de");
+		let text: FormattedText = quick_xml::de::from_str(r#"<text enclosure="inverted-bracket">a  b</text>"#).unwrap();
+		assert_eq!(text.text(), "a b");
+		assert_eq!(text.enclosure, Some(EnclosureShape::InvertedBracket));
+	}
+
+	#[test]
+	fn lyric_verse() {
+		let lyric = |xml: &str| -> Lyric { quick_xml::de::from_str(xml).unwrap() };
+		let lyrics = [
+			lyric("<lyric><syllabic>begin</syllabic><text>Hap</text></lyric>"),
+			lyric("<lyric><syllabic>end</syllabic><text>py</text><extend/></lyric>"),
+			lyric("<lyric><extend/></lyric>"),
+			lyric(r#"<lyric number="2"><syllabic>single</syllabic><text>Good</text></lyric>"#),
+			lyric("<lyric><syllabic>single</syllabic><text>day</text><end-line/></lyric>"),
+			lyric("<lyric><syllabic>single</syllabic><text>to</text></lyric>"),
+			lyric("<lyric><syllabic>single</syllabic><text>you</text><elision/><syllabic>single</syllabic><text>all</text></lyric>"),
+		];
+		assert!(lyrics[2].is_melisma());
+		assert!(!lyrics[1].is_melisma());
+		assert_eq!(lyrics[3].number(), "2");
+
+		let verse = Lyric::verse(lyrics.iter().enumerate(), "1");
+		assert_eq!(verse.text, "Happy day\nto you all");
+		assert_eq!(verse.anchors, vec![(0, 0), (3, 1), (6, 4), (10, 5), (13, 6)]);
+		let verse = Lyric::verse(lyrics.iter().enumerate(), "2");
+		assert_eq!(verse.text, "Good");
+		assert_eq!(verse.anchors, vec![(0, 3)]);
+	}
+
+	#[test]
+	fn frame_chord_shape() {
+		let frame: Frame = quick_xml::de::from_str(concat!(
+			"<frame><frame-strings>6</frame-strings><frame-frets>4</frame-frets><first-fret>3</first-fret>",
+			r#"<frame-note><string>6</string><fret>1</fret><fingering>1</fingering><barre type="start"/></frame-note>"#,
+			"<frame-note><string>5</string><fret>3</fret><fingering>3</fingering></frame-note>",
+			"<frame-note><string>4</string><fret>3</fret><fingering>4</fingering></frame-note>",
+			"<frame-note><string>3</string><fret>2</fret><fingering>2</fingering></frame-note>",
+			"<frame-note><string>7</string><fret>0</fret></frame-note>",
+			"</frame>",
+		))
+		.unwrap();
+		let shape = frame.chord_shape().unwrap();
+		assert_eq!(shape.first_fret, 3);
+		assert_eq!(shape.fret_count, 4);
+		assert_eq!(shape.frets, vec![Some(1), Some(3), Some(3), Some(2), None, None]);
+		assert_eq!(shape.fingers[0].as_deref(), Some("1"));
+		assert_eq!(shape.fingers[4], None);
+		// The barre is never stopped, so it extends to the first string.
+		assert_eq!(shape.barres, vec![ChordBarre { fret: 1, from_string: 6, to_string: 1 }]);
+
+		let frame: Frame = quick_xml::de::from_str(concat!(
+			"<frame><frame-strings>4000000000</frame-strings><frame-frets>4</frame-frets>",
+			"<frame-note><string>1</string><fret>0</fret></frame-note></frame>",
+		))
+		.unwrap();
+		assert_eq!(frame.first_fret(), 1);
+		assert!(frame.chord_shape().is_none());
+	}
+
+	#[test]
+	fn midi_instrument_controllers() {
+		let instrument = |attributes: &str| -> MidiInstrument {
+			quick_xml::de::from_str(&format!(r#"<midi-instrument id="P1-I1">{}</midi-instrument>"#, attributes)).unwrap()
+		};
+		let pan = |degrees: i16| instrument(&format!("<pan>{}</pan>", degrees)).pan_controller().unwrap();
+		assert_eq!(pan(-90), 0);
+		assert_eq!(pan(0), 63);
+		assert_eq!(pan(90), 127);
+		assert_eq!(pan(180), 63);
+		assert_eq!(pan(-135), 31);
+		assert_eq!(pan(135), 95);
+		assert_eq!(pan(1000), 63);
+		assert_eq!(pan(i16::MIN), 63);
+		assert_eq!(pan(i16::MAX), 63);
+		assert_eq!(instrument("<volume>50</volume>").volume_controller(), Some(63));
+		assert_eq!(instrument("<volume>100</volume>").volume_controller(), Some(127));
+		assert_eq!(instrument("<volume>250</volume>").volume_controller(), Some(127));
+		assert_eq!(instrument("").pan_controller(), None);
+	}
+
+	#[test]
+	fn score_part_midi() {
+		let part: ScorePart = quick_xml::de::from_str(concat!(
+			r#"<score-part id="P1"><part-name>Piano</part-name>"#,
+			r#"<midi-device port="2">Synth</midi-device><midi-device id="P1-I2" port="3"/>"#,
+			r#"<midi-instrument id="P1-I1"><midi-channel>1</midi-channel></midi-instrument>"#,
+			r#"<midi-instrument id="P1-I2"><midi-channel>2</midi-channel><midi-program>41</midi-program></midi-instrument>"#,
+			"</score-part>",
+		))
+		.unwrap();
+		assert_eq!(part.part_name.value, "Piano");
+		assert_eq!(part.midi_program(), Midi128::new(41));
+		assert_eq!(part.midi_program().unwrap().to_midi(), 40);
+		assert_eq!(part.midi_instrument("P1-I2").and_then(|instrument| instrument.midi_channel), Midi16::new(2));
+		assert!(part.midi_instrument("P1-I3").is_none());
+		assert_eq!(part.midi_device("P1-I2").and_then(|device| device.port), Midi16::new(3));
+		assert_eq!(part.midi_device("P1-I1").map(|device| device.value.as_str()), Some("Synth"));
+	}
+}
@@ -1,4 +1,5 @@
 pub use simple_types::*;
+pub use complex_types::*;
 
 mod simple_types;
-mod complex_types;
\ No newline at end of file
+mod complex_types;
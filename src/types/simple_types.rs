@@ -1,8 +1,181 @@
-use serde::Deserialize;
+use std::fmt;
+use std::str::FromStr;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use validator::{Validate, ValidationError};
 use lazy_static::lazy_static;
 use regex::Regex;
 
+/// Defines an enumerated simple type from its variants and their MusicXML spellings, deriving the common traits and implementing Display, FromStr, Serialize, and Deserialize in terms of those spellings. A trailing `_ => Other(String),` arm makes the type open-ended: unrecognized spellings are kept in that variant instead of failing to parse.
+macro_rules! simple_enum {
+	($(#[$meta:meta])* pub enum $name:ident { $($(#[$variant_meta:meta])* $variant:ident => $value:literal,)* _ => $other:ident(String), }) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+		pub enum $name {
+			$($(#[$variant_meta])* $variant,)*
+			$other(String),
+		}
+
+		impl $name {
+			fn from_value(text: String) -> Self {
+				match text.as_str() {
+					$($value => $name::$variant,)*
+					_ => $name::$other(text),
+				}
+			}
+
+			/// Returns the MusicXML spelling of this value.
+			pub fn as_str(&self) -> &str {
+				match self {
+					$($name::$variant => $value,)*
+					$name::$other(text) => text,
+				}
+			}
+
+			/// Returns whether this is one of the values defined by MusicXML rather than an application-specific one.
+			pub fn is_known(&self) -> bool {
+				!matches!(self, $name::$other(_))
+			}
+		}
+
+		impl FromStr for $name {
+			type Err = ValidationError;
+
+			fn from_str(text: &str) -> Result<Self, Self::Err> {
+				Ok($name::from_value(text.to_string()))
+			}
+		}
+
+		impl fmt::Display for $name {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str(self.as_str())
+			}
+		}
+
+		impl Serialize for $name {
+			fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				serializer.serialize_str(self.as_str())
+			}
+		}
+
+		impl<'de> Deserialize<'de> for $name {
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				String::deserialize(deserializer).map($name::from_value)
+			}
+		}
+	};
+	($(#[$meta:meta])* pub enum $name:ident { $($(#[$variant_meta:meta])* $variant:ident => $value:literal,)* }) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+		pub enum $name {
+			$($(#[$variant_meta])* $variant,)*
+		}
+
+		impl $name {
+			const VALUES: &'static [&'static str] = &[$($value,)*];
+
+			/// Returns the MusicXML spelling of this value.
+			pub fn as_str(&self) -> &'static str {
+				match self {
+					$($name::$variant => $value,)*
+				}
+			}
+		}
+
+		impl FromStr for $name {
+			type Err = ValidationError;
+
+			fn from_str(text: &str) -> Result<Self, Self::Err> {
+				match text {
+					$($value => Ok($name::$variant),)*
+					_ => Err(ValidationError::new(concat!("Invalid ", stringify!($name)))),
+				}
+			}
+		}
+
+		impl fmt::Display for $name {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str(self.as_str())
+			}
+		}
+
+		impl Serialize for $name {
+			fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				serializer.serialize_str(self.as_str())
+			}
+		}
+
+		impl<'de> Deserialize<'de> for $name {
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				let text = String::deserialize(deserializer)?;
+				text.parse().map_err(|_| de::Error::unknown_variant(&text, $name::VALUES))
+			}
+		}
+	};
+}
+
+/// Defines a range-validated integer type for MIDI 1.0 values that are numbered from 1, implementing Display, FromStr, Serialize, and Deserialize in terms of the number. Values outside the range fail to parse.
+macro_rules! midi_value {
+	($(#[$meta:meta])* pub struct $name:ident($inner:ty) = 1..=$max:literal;) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+		pub struct $name($inner);
+
+		impl $name {
+			/// The largest allowed value.
+			pub const MAX: $inner = $max;
+
+			/// Creates a value, returning None if it is outside the allowed range.
+			pub fn new(value: $inner) -> Option<Self> {
+				if (1..=$max).contains(&value) {
+					Some($name(value))
+				} else {
+					None
+				}
+			}
+
+			/// Returns the value as numbered in MusicXML, starting from 1.
+			pub fn get(self) -> $inner {
+				self.0
+			}
+
+			/// Returns the value as sent in MIDI messages, starting from 0.
+			pub fn to_midi(self) -> $inner {
+				self.0 - 1
+			}
+		}
+
+		impl FromStr for $name {
+			type Err = ValidationError;
+
+			fn from_str(text: &str) -> Result<Self, Self::Err> {
+				text.trim()
+					.parse()
+					.ok()
+					.and_then($name::new)
+					.ok_or_else(|| ValidationError::new(concat!("Invalid ", stringify!($name))))
+			}
+		}
+
+		impl fmt::Display for $name {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				self.0.fmt(f)
+			}
+		}
+
+		impl Serialize for $name {
+			fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				serializer.serialize_str(&self.to_string())
+			}
+		}
+
+		impl<'de> Deserialize<'de> for $name {
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				deserialize_from_str(deserializer)
+			}
+		}
+	};
+}
+
 /// The accordion-middle type may have values of 1, 2, or 3, corresponding to having 1 to 3 dots in the middle section of the accordion registration symbol.
 pub type AccordionMiddle = u8;
 
@@ -12,42 +185,30 @@ pub type BeamLevel = u8;
 /// The color type indicates the color of an element. Color may be represented as hexadecimal RGB triples, as in HTML, or as hexadecimal ARGB tuples, with the A indicating alpha of transparency. An alpha value of 00 is totally transparent; FF is totally opaque. If RGB is used, the A value is assumed to be FF.  For instance, the RGB value "#800080" represents purple. An ARGB value of "#40800080" would be a transparent purple.  As in SVG 1.1, colors are defined in terms of the sRGB color space (IEC 61966).
 pub type Color = String;
 
-/// The comma-separated-text type is used to specify a comma-separated list of text elements, as is used by the font-family attribute.
-pub type CommaSeparatedText = String;
-
-/// The distance-type defines what type of distance is being defined in a distance element. Values include beam and hyphen. This is left as a string so that other application-specific types can be defined, but it is made a separate type so that it can be redefined more strictly.
-pub type DistanceType = String;
-
 /// The divisions type is used to express values in terms of the musical divisions defined by the divisions element. It is preferred that these be integer values both for MIDI interoperability and to avoid roundoff errors.
 pub type Divisions = f64;
 
-/// The ending-number type is used to specify either a comma-separated list of positive integers without leading zeros, or a string of zero or more spaces. It is used for the number attribute of the ending element. The zero or more spaces version is used when software knows that an ending is present, but cannot determine the type of the ending.
-pub type EndingNumber = String;
-
 /// The fifths type represents the number of flats or sharps in a traditional key signature. Negative numbers are used for flats and positive numbers for sharps, reflecting the key's placement within the circle of fifths (hence the type name).
 pub type Fifths = i64;
 
-/// The font-size can be one of the CSS font sizes or a numeric point size.
-pub type FontSize = f64;
-
-/// The line-width-type defines what type of line is being defined in a line-width element. Values include beam, bracket, dashes, enclosure, ending, extend, heavy barline, leger, light barline, octave shift, pedal, slur middle, slur tip, staff, stem, tie middle, tie tip, tuplet bracket, and wedge. This is left as a string so that other application-specific types can be defined, but it is made a separate type so that it can be redefined more strictly.
-pub type LineWidthType = String;
-
-/// The midi-16 type is used to express MIDI 1.0 values that range from 1 to 16.
-pub type Midi16 = u8;
+midi_value! {
+	/// The midi-16 type is used to express MIDI 1.0 values that range from 1 to 16.
+	pub struct Midi16(u8) = 1..=16;
+}
 
-/// The midi-128 type is used to express MIDI 1.0 values that range from 1 to 128.
-pub type Midi128 = u8;
+midi_value! {
+	/// The midi-128 type is used to express MIDI 1.0 values that range from 1 to 128.
+	pub struct Midi128(u8) = 1..=128;
+}
 
-/// The midi-16384 type is used to express MIDI 1.0 values that range from 1 to 16,384.
-pub type Midi16384 = u16;
+midi_value! {
+	/// The midi-16384 type is used to express MIDI 1.0 values that range from 1 to 16,384.
+	pub struct Midi16384(u16) = 1..=16384;
+}
 
 /// The millimeters type is a number representing millimeters. This is used in the scaling element to provide a default scaling from tenths to physical units.
 pub type Millimeters = f64;
 
-/// The mode type is used to specify major/minor and other mode distinctions. Valid mode values include major, minor, dorian, phrygian, lydian, mixolydian, aeolian, ionian, locrian, and none.
-pub type Mode = String;
-
 /// The non-negative-decimal type specifies a non-negative decimal value.
 pub type NonNegativeDecimal = f64;
 
@@ -57,6 +218,9 @@ pub type NumberLevel = u8;
 /// The number-of-lines type is used to specify the number of lines in text decoration attributes.
 pub type NumberOfLines = u8;
 
+/// The numeral-value type represents a Roman numeral or Nashville number value as a positive integer from 1 to 7.
+pub type NumeralValue = u8;
+
 /// Octaves are represented by the numbers 0 to 9, where 4 indicates the octave started by middle C.
 pub type Octave = u8;
 
@@ -87,9 +251,6 @@ pub type StringNumber = u64;
 /// The tenths type is a number representing tenths of interline staff space (positive or negative). Both integer and decimal values are allowed, such as 5 for a half space and 2.5 for a quarter space. Interline space is measured from the middle of a staff line.  Distances in a MusicXML file are measured in tenths of staff space. Tenths are then scaled to millimeters within the scaling element, used in the defaults element at the start of a score. Individual staves can apply a scaling factor to adjust staff size. When a MusicXML element or attribute refers to tenths, it means the global tenths defined by the scaling element, not the local tenths as adjusted by the staff-size element.
 pub type Tenths = f64;
 
-/// The time-only type is used to indicate that a particular playback-related element only applies particular times through a repeated section. The value is a comma-separated list of positive integers arranged in ascending order, indicating which times through the repeated section that the element applies.
-pub type TimeOnly = String;
-
 /// The number of tremolo marks is represented by a number from 0 to 8: the same as beam-level with 0 added.
 pub type TremoloMarks = u8;
 
@@ -97,7 +258,7 @@ pub type TremoloMarks = u8;
 pub type TrillBeats = f64;
 
 /// Calendar dates are represented yyyy-mm-dd format, following ISO 8601. This is a W3C XML Schema date type, but without the optional timezone data.
-pub type YYYYMMDD = String;
+pub type YYYYMMDD = Date;
 
 fn validate_color(color: &str) -> Result<(), ValidationError> {
 	lazy_static! {
@@ -112,7 +273,7 @@ fn validate_color(color: &str) -> Result<(), ValidationError> {
 
 fn validate_comma_separated_text(text: &str) -> Result<(), ValidationError> {
 	lazy_static! {
-		static ref CST_RE: Regex = Regex::new(r"[^,]+(, ?[^,]+)*").unwrap();
+		static ref CST_RE: Regex = Regex::new(r"^[^,]+(, ?[^,]+)*$").unwrap();
 	}
 	if CST_RE.is_match(text) {
 		Ok(())
@@ -123,7 +284,7 @@ fn validate_comma_separated_text(text: &str) -> Result<(), ValidationError> {
 
 fn validate_ending_number(text: &str) -> Result<(), ValidationError> {
 	lazy_static! {
-		static ref ENDN_RE: Regex = Regex::new(r"([ ]*)").unwrap();
+		static ref ENDN_RE: Regex = Regex::new(r"^( *|[1-9][0-9]*(, ?[1-9][0-9]*)*)$").unwrap();
 	}
 	if ENDN_RE.is_match(text) {
 		Ok(())
@@ -134,7 +295,7 @@ fn validate_ending_number(text: &str) -> Result<(), ValidationError> {
 
 fn validate_time_only(text: &str) -> Result<(), ValidationError> {
 	lazy_static! {
-		static ref TO_RE: Regex = Regex::new(r"[1-9][0-9]*(, ?[1-9][0-9]*)*").unwrap();
+		static ref TO_RE: Regex = Regex::new(r"^[1-9][0-9]*(, ?[1-9][0-9]*)*$").unwrap();
 	}
 	if TO_RE.is_match(text) {
 		Ok(())
@@ -145,1097 +306,1924 @@ fn validate_time_only(text: &str) -> Result<(), ValidationError> {
 
 fn validate_yyyy_mm_dd(text: &str) -> Result<(), ValidationError> {
 	lazy_static! {
-		static ref YMD_RE: Regex = Regex::new(r"[^:Z]*").unwrap();
+		static ref YMD_RE: Regex = Regex::new(r"^-?[0-9]{4,}-[0-9]{2}-[0-9]{2}$").unwrap();
 	}
 	if YMD_RE.is_match(text) {
 		Ok(())
 	} else {
-		Err(ValidationError::new("Invalid time only"))
+		Err(ValidationError::new("Invalid date"))
 	}
 }
 
+fn validate_smufl_glyph_name(text: &str) -> Result<(), ValidationError> {
+	lazy_static! {
+		static ref SMUFL_RE: Regex = Regex::new(r"^[\w.:-]+$").unwrap();
+	}
+	if SMUFL_RE.is_match(text) {
+		Ok(())
+	} else {
+		Err(ValidationError::new("Invalid SMuFL glyph name"))
+	}
+}
 
+fn parse_positive_integers(text: &str) -> Option<Vec<u32>> {
+	text.split(',')
+		.map(|number| number.trim_start().parse().ok())
+		.collect()
+}
 
+fn deserialize_from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+	D: Deserializer<'de>,
+	T: FromStr<Err = ValidationError>,
+{
+	let text = String::deserialize(deserializer)?;
+	text.parse().map_err(|e: ValidationError| de::Error::custom(e.code))
+}
 
-/// The above-below type is used to indicate whether one element appears above or below another element.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum AboveBelow {
-	Above,
-	Below
-}
-
-/// The accidental-value type represents notated accidentals supported by MusicXML. In the MusicXML 2.0 DTD this was a string with values that could be included. The XSD strengthens the data typing to an pub enumerated list. The quarter- and three-quarters- accidentals are Tartini-style quarter-tone accidentals. The -down and -up accidentals are quarter-tone accidentals that include arrows pointing down or up. The slash- accidentals are used in Turkish classical music. The numbered sharp and flat accidentals are superscripted versions of the accidental signs, used in Turkish folk music. The sori and koron accidentals are microtonal sharp and flat accidentals used in Iranian and Persian music.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "kebab-case")]
-pub enum AccidentalValue {
-	Sharp,
-	Natural,
-	Flat,
-	DoubleSharp,
-	SharpSharp,
-	FlatFlat,
-	NaturalSharp,
-	NaturalFlat,
-	QuarterFlat,
-	ThreeQuartersFlat,
-	SharpDown,
-	SharpUp,
-	NaturalDown,
-	NaturalUp,
-	FlatDown,
-	FlatUp,
-	TripleSharp,
-	TripleFlat,
-	SlashQuarterSharp,
-	SlashSharp,
-	SlashFlat,
-	DoubleSlashFlat,
-	Sharp1,
-	Sharp2,
-	Sharp3,
-	Sharp5,
-	Flat1,
-	Flat2,
-	Flat3,
-	Flat4,
-	Sori,
-	Koron
-}
-
-/// The arrow-direction type represents the direction in which an arrow points, using Unicode arrow terminology.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum ArrowDirection {
-	Left,
-	Up,
-	Right,
-	Down,
-	Northwest,
-	Northeast,
-	Southeast,
-	Southwest,
-	#[serde(rename = "left right")]
-	LeftRight,
-	#[serde(rename = "up down")]
-	UpDown,
-	#[serde(rename = "northwest southeast")]
-	NorthwestSoutheast,
-	#[serde(rename = "northeast southwest")]
-	NortheastSouthwest,
-	Other
-}
-
-/// The arrow-style type represents the style of an arrow, using Unicode arrow terminology. Filled and hollow arrows indicate polygonal single arrows. Paired arrows are duplicate single arrows in the same direction. Combined arrows apply to double direction arrows like left right, indicating that an arrow in one direction should be combined with an arrow in the other direction.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum ArrowStyle {
-	Single,
-	Double,
-	Filled,
-	Hollow,
-	Paired,
-	Combined,
-	Other
-}
-
-/// The backward-forward type is used to specify repeat directions. The start of the repeat has a forward direction while the end of the repeat has a backward direction.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum BackwardForward {
-	Backward,
-	Forward
-}
-
-/// The bar-style type represents barline style information. Choices are regular, dotted, dashed, heavy, light-light, light-heavy, heavy-light, heavy-heavy, tick (a short stroke through the top line), short (a partial barline between the 2nd and 4th lines), and none.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "kebab-case")]
-pub enum BarStyle {
-	Regular,
-	Dotted,
-	Dashed,
-	Heavy,
-	LightLight,
-	LightHeavy,
-	HeavyLight,
-	HeavyHeavy,
-	Tick,
-	Short,
-	None
-}
-
-/// The beater-value type represents pictograms for beaters, mallets, and sticks that do not have different materials represented in the pictogram. The finger and hammer values are in addition to Stone's list.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum BeaterValue {
-	Bow,
-	#[serde(rename = "chime hammer")]
-	ChimeHammer,
-	Coin,
-	Finger,
-	Fingernail,
-	Fist,
-	#[serde(rename = "guiro scraper")]
-	GuiroScraper,
-	Hammer,
-	Hand,
-	#[serde(rename = "jazz stick")]
-	JazzStick,
-	#[serde(rename = "knitting needle")]
-	KnittingNeedle,
-	#[serde(rename = "metal hammer")]
-	MetalHammer,
-	#[serde(rename = "snare stick")]
-	SnareStick,
-	#[serde(rename = "spoon mallet")]
-	SpoonMallet,
-	#[serde(rename = "triangle beater")]
-	TriangleBeater,
-	#[serde(rename = "triangle beater plain")]
-	TriangleBeaterPlain,
-	#[serde(rename = "wire brush")]
-	WireBrush,
-}
-
-/// The breath-mark-value type represents the symbol used for a breath mark.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum BreathMarkValue {
-	Comma,
-	Tick
-}
-
-/// The cancel-location type is used to indicate where a key signature cancellation appears relative to a new key signature: to the left, to the right, or before the barline and to the left. It is left by default. For mid-measure key elements, a cancel-location of before-barline should be treated like a cancel-location of left.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "kebab-case")]
-pub enum CancelLocation {
-	Left,
-	Right,
-	BeforeBarline,
-}
-
-/// The circular-arrow type represents the direction in which a circular arrow points, using Unicode arrow terminology.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum CircularArrow {
-	Clockwise,
-	Anticlockwise,
-}
-
-/// The clef-sign element represents the different clef symbols. The jianpu sign indicates that the music that follows should be in jianpu numbered notation, just as the TAB sign indicates that the music that follows should be in tablature notation. Unlike TAB, a jianpu sign does not correspond to a visual clef notation.
-#[derive(Debug, Deserialize, PartialEq)]
-pub enum ClefSign {
-	G,
-	F,
-	C,
-	#[serde(rename = "percussion")]
-	Percussion,
-	TAB,
-	#[serde(rename = "jianpu")]
-	Jianpu,
-	#[serde(rename = "none")]
-	None,
-}
-
-/// The css-font-size type includes the CSS font sizes used as an alternative to a numeric point size.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "kebab-case")]
-pub enum CssFontSize {
-	XxSmall,
-	XSMall,
-	Small,
-	Medium,
-	Large,
-	XLarge,
-	#[serde(rename = "xx-large")]
-	XXLarge,
-}
-
-/// The degree-symbol-value type indicates indicates that a symbol should be used in specifying the degree.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "kebab-case")]
-pub enum DegreeSymbolValue {
-	Major,
-	Minor,
-	Augmented,
-	Diminished,
-	HalfDiminished,
-}
-
-/// The degree-type-value type indicates whether the current degree element is an addition, alteration, or subtraction to the kind of the current chord in the harmony element.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum DegreeTypeValue {
-	Add,
-	Alter,
-	Subtract,
-}
-
-/// The effect type represents pictograms for sound effect percussion instruments. The cannon value is in addition to Stone's list.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum Effect {
-	Anvil,
-	#[serde(rename = "auto horn")]
-	AutoHorn,
-	#[serde(rename = "bird whistle")]
-	BirdWhistle,
-	Cannon,
-	#[serde(rename = "duck call")]
-	DuckCall,
-	#[serde(rename = "gun shot")]
-	GunShot,
-	#[serde(rename = "klaxon horn")]
-	KlaxonHorn,
-	#[serde(rename = "lions roar")]
-	LionsRoar,
-	#[serde(rename = "police whistle")]
-	PoliceWhistle,
-	Siren,
-	#[serde(rename = "slide whistle")]
-	SlideWhistle,
-	#[serde(rename = "thunder sheet")]
-	ThunderSheet,
-	#[serde(rename = "wind machine")]
-	WindMachine,
-	#[serde(rename = "wind whistle")]
-	WindWhistle,
-}
-
-/// The enclosure-shape type describes the shape and presence / absence of an enclosure around text or symbols. A bracket enclosure is similar to a rectangle with the bottom line missing, as is common in jazz notation.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum EnclosureShape {
-	Rectangle,
-	Square,
-	Oval,
-	Circle,
-	Bracket,
-	Triangle,
-	Diamond,
-	None,
-}
-
-/// The fan type represents the type of beam fanning present on a note, used to represent accelerandos and ritardandos.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum Fan {
-	Accel,
-	Rit,
-	None,
-}
-
-/// The fermata-shape type represents the shape of the fermata sign. The empty value is equivalent to the normal value.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum FermataShape {
-	Normal,
-	Angled,
-	Square,
-}
-
-/// The font-style type represents a simplified version of the CSS font-style property.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum FontStyle {
-	Normal,
-	Italic,
-}
-
-/// The font-weight type represents a simplified version of the CSS font-weight property.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum FontWeight {
-	Normal,
-	Bold,
-}
-
-/// The glass type represents pictograms for glass percussion instruments.
-#[derive(Debug, Deserialize, PartialEq)]
-pub enum Glass {
-	#[serde(rename = "wind chimes")]
-	WindChimes,
-}
-
-/// The group-barline-value type indicates if the group should have common barlines.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum GroupBarlineValue {
-	Yes,
-	No,
-	#[serde(rename = "Mensurstrich")]
-	Mensurstrich,
-}
-
-/// The group-symbol-value type indicates how the symbol for a group is indicated in the score. The default value is none.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum GroupSymbolValue {
-	None,
-	Brace,
-	Line,
-	Bracket,
-	Square,
-}
-
-/// The handbell-value type represents the type of handbell technique being notated.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum HandbellValue {
-	Damp,
-	Echo,
-	Gyro,
-	#[serde(rename = "hand martellato")]
-	HandMartellato,
-	#[serde(rename = "mallet lift")]
-	MalletLift,
-	#[serde(rename = "mallet table")]
-	MalletTable,
-	Martellato,
-	#[serde(rename = "martellato lift")]
-	MartellatoLift,
-	#[serde(rename = "muted martellato")]
-	MutedMartellato,
-	#[serde(rename = "pluck lift")]
-	PluckLift,
-	Swing,
-}
-
-/// The harmony-type type differentiates different types of harmonies when alternate harmonies are possible. Explicit harmonies have all notes present in the music; implied have some notes missing but implied; alternate represents alternate analyses.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum HarmonyType {
-	Explicit,
-	Implied,
-	Alternate,
-}
-
-/// The hole-closed-location type indicates which portion of the hole is filled in when the corresponding hole-closed-value is half.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum HoleClosedLocation {
-	Right,
-	Bottom,
-	Left,
-	Top,
-}
-
-/// The hole-closed-value type represents whether the hole is closed, open, or half-open.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum HoleClosedValue {
-	Yes,
-	No,
-	Half,
-}
-
-/// A kind-value indicates the type of chord. Degree elements can then add, subtract, or alter from these starting points. Values include:  Triads: major (major third, perfect fifth) minor (minor third, perfect fifth) augmented (major third, augmented fifth) diminished (minor third, diminished fifth) Sevenths: dominant (major triad, minor seventh) major-seventh (major triad, major seventh) minor-seventh (minor triad, minor seventh) diminished-seventh (diminished triad, diminished seventh) augmented-seventh (augmented triad, minor seventh) half-diminished (diminished triad, minor seventh) major-minor (minor triad, major seventh) Sixths: major-sixth (major triad, added sixth) minor-sixth (minor triad, added sixth) Ninths: dominant-ninth (dominant-seventh, major ninth) major-ninth (major-seventh, major ninth) minor-ninth (minor-seventh, major ninth) 11ths (usually as the basis for alteration): dominant-11th (dominant-ninth, perfect 11th) major-11th (major-ninth, perfect 11th) minor-11th (minor-ninth, perfect 11th) 13ths (usually as the basis for alteration): dominant-13th (dominant-11th, major 13th) major-13th (major-11th, major 13th) minor-13th (minor-11th, major 13th) Suspended: suspended-second (major second, perfect fifth) suspended-fourth (perfect fourth, perfect fifth) Functional sixths: Neapolitan Italian French German Other: pedal (pedal-point bass) power (perfect fifth) Tristan  The "other" kind is used when the harmony is entirely composed of add elements. The "none" kind is used to explicitly encode absence of chords or functional harmony.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "kebab-case")]
-pub enum KindValue {
-	Major,
-	Minor,
-	Augmented,
-	Diminished,
-	Dominant,
-	MajorSeventh,
-	MinorSeventh,
-	DiminishedSeventh,
-	AugmentedSeventh,
-	HalfDiminished,
-	MajorMinor,
-	MajorSixth,
-	MinorSixth,
-	DominantRinth,
-	MajorRinth,
-	MinorRinth,
-	#[serde(rename = "dominant-11th")]
-	Dominant11th,
-	#[serde(rename = "major-11th")]
-	Major11th,
-	#[serde(rename = "minor-11th")]
-	Minor11th,
-	#[serde(rename = "dominant-13th")]
-	Dominant13th,
-	#[serde(rename = "major-13th")]
-	Major13th,
-	#[serde(rename = "minor-13th")]
-	Minor13th,
-	SuspendedSecond,
-	SuspendedFourth,
-	#[serde(rename = "Neapolitan")]
-	Neapolitan,
-	#[serde(rename = "Italian")]
-	Italian,
-	#[serde(rename = "French")]
-	French,
-	#[serde(rename = "German")]
-	German,
-	Pedal,
-	Power,
-	#[serde(rename = "Tristan")]
-	Tristan,
-	Other,
-	None,
-}
-
-/// The left-center-right type is used to define horizontal alignment and text justification.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum LeftCenterRight {
-	Left,
-	Center,
-	Right,
-}
-
-/// The left-right type is used to indicate whether one element appears to the left or the right of another element.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum LeftRight {
-	Left,
-	Right,
-}
-
-/// The line-end type specifies if there is a jog up or down (or both), an arrow, or nothing at the start or end of a bracket.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum LineEnd {
-	Up,
-	Down,
-	Both,
-	Arrow,
-	None,
-}
-
-/// The line-shape type distinguishes between straight and curved lines.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum LineShape {
-	Straight,
-	Curved,
-}
-
-/// The line-type type distinguishes between solid, dashed, dotted, and wavy lines.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum LineType {
-	Solid,
-	Dashed,
-	Dotted,
-	Wavy,
-}
-
-/// The margin-type type specifies whether margins apply to even page, odd pages, or both.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum MarginType {
-	Odd,
-	Even,
-	Both,
-}
-
-/// The measure-numbering-value type describes how measure numbers are displayed on this part: no numbers, numbers every measure, or numbers every system.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum MeasureNumbering {
-	None,
-	Measure,
-	System,
-}
-
-/// The membrane type represents pictograms for membrane percussion instruments. The goblet drum value is in addition to Stone's list.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum Membrane {
-	#[serde(rename = "bass drum")]
-	BassDrum,
-	#[serde(rename = "bass drum on side")]
-	BassDrumOnSide,
-	#[serde(rename = "bongos")]
-	Bongos,
-	#[serde(rename = "conga drum")]
-	CongaDrum,
-	#[serde(rename = "goblet drum")]
-	GobletDrum,
-	#[serde(rename = "military drum")]
-	MilitaryDrum,
-	#[serde(rename = "snare drum")]
-	SnareDrum,
-	#[serde(rename = "bass drum snares off")]
-	SnareDrumSnaresOff,
-	#[serde(rename = "tambourine")]
-	Tambourine,
-	#[serde(rename = "tenor drum")]
-	TenorDrum,
-	Timbales,
-	Tomtom,
-}
-
-/// The metal type represents pictograms for metal percussion instruments. The hi-hat value refers to a pictogram like Stone's high-hat cymbals but without the long vertical line at the bottom.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "kebab-case")]
-pub enum Metal {
-	Almglocken,
-	Bell,
-	#[serde(rename = "bell plate")]
-	BellPlate,
-	#[serde(rename = "brake drum")]
-	BrakeDrum,
-	#[serde(rename = "Chinese cymbal")]
-	ChineseCymbal,
-	Cowbell,
-	#[serde(rename = "crash cymbals")]
-	CrashCymbals,
-	Crotale,
-	#[serde(rename = "cymbal tongs")]
-	CymbalTongs,
-	#[serde(rename = "doomed gong")]
-	DoomedGong,
-	#[serde(rename = "finger cymbals")]
-	FingerCymbals,
-	Flexatone,
-	Gong,
-	HiHat,
-	HighHatCymbals,
-	Handbell,
-	Sistrum,
-	#[serde(rename = "sizzle cymbal")]
-	SizzleCymbal,
-	#[serde(rename = "sleigh bells")]
-	SleighBells,
-	#[serde(rename = "suspended cymbal")]
-	SuspendedCymbal,
-	#[serde(rename = "tam tam")]
-	TamTam,
-	Triangle,
-	#[serde(rename = "Vietnamese hat")]
-	VietnameseHat,
-}
-
-/// The mute type represents muting for different instruments, including brass, winds, and strings. The on and off values are used for undifferentiated mutes. The remaining values represent specific mutes.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "kebab-case")]
-pub enum Mute {
-	On,
-	Off,
-	Straight,
-	Cup,
-	HarmonNoStem,
-	HarmonStem,
-	Bucket,
-	Plunger,
-	Hat,
-	Solotone,
-	Practice,
-	StopMute,
-	StopHand,
-	Echo,
-	Palm,
-}
-
-/// The notehead type indicates shapes other than the open and closed ovals associated with note durations. The values do, re, mi, fa, fa up, so, la, and ti correspond to Aikin's 7-shape system. The fa up shape is typically used with upstems; the fa shape is typically used with downstems or no stems.  The arrow shapes differ from triangle and inverted triangle by being centered on the stem. Slashed and back slashed notes include both the normal notehead and a slash. The triangle shape has the tip of the triangle pointing up; the inverted triangle shape has the tip of the triangle pointing down. The left triangle shape is a right triangle with the hypotenuse facing up and to the left.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "kebab-case")]
-pub enum NoteHeadValue {
-	Slash,
-	Triangle,
-	Diamond,
-	Square,
-	Cross,
-	X,
-	CircleX,
-	#[serde(rename = "inverted triangle")]
-	InvertedTriangle,
-	#[serde(rename = "arrow down")]
-	ArrowDown,
-	#[serde(rename = "arrow up")]
-	ArrowUp,
-	Slashed,
-	#[serde(rename = "back slashed")]
-	BackSlashed,
-	Normal,
-	Cluster,
-	#[serde(rename = "circle dot")]
-	CircleDot,
-	#[serde(rename = "left triangle")]
-	LeftTriangle,
-	Rectangle,
-	None,
-	Do,
-	Re,
-	Mi,
-	Fa,
-	#[serde(rename = "fa up")]
-	FaUp,
-	So,
-	La,
-	Ti,
-}
-
-/// The note-size-type type indicates the type of note being defined by a note-size element. The grace type is used for notes of cue size that that include a grace element. The cue type is used for all other notes with cue size, whether defined explicitly or implicitly via a cue element. The large type is used for notes of large size.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum NoteSizeType {
-	Cue,
-	Grace,
-	Large,
-}
-
-/// The note-type type is used for the MusicXML type element and represents the graphic note type, from 1024th (shortest) to maxima (longest).
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum NoteTypeValue {
-	#[serde(rename = "1024th")]
-	_1024th,
-	#[serde(rename = "512th")]
-	_512th,
-	#[serde(rename = "256th")]
-	_256th,
-	#[serde(rename = "128th")]
-	_128th,
-	#[serde(rename = "64th")]
-	_64th,
-	#[serde(rename = "32th")]
-	_32th,
-	#[serde(rename = "16th")]
-	_16th,
-	Eight,
-	Quarter,
-	Half,
-	Whole,
-	Breve,
-	Long,
-	Maxima,
+/// The comma-separated-text type is used to specify a comma-separated list of text elements, as is used by the font-family attribute.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommaSeparatedText {
+	text: String,
+	values: Vec<String>,
 }
 
-/// The number-or-normal values can be either a decimal number or the string "normal". This is used by the line-height and letter-spacing attributes.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(untagged)]
-pub enum NumberOrNormal {
-	Number(f64),
-	Normal(String),
+impl CommaSeparatedText {
+	/// Returns the text elements of the list, without the separating commas and spaces.
+	pub fn values(&self) -> &[String] {
+		&self.values
+	}
 }
 
-/// The on-off type is used for notation elements such as string mutes.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum OnOff {
-	On,
-	Off,
+impl FromStr for CommaSeparatedText {
+	type Err = ValidationError;
+
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		validate_comma_separated_text(text)?;
+		Ok(CommaSeparatedText {
+			text: text.to_string(),
+			values: text.split(',').map(|value| value.trim_start().to_string()).collect(),
+		})
+	}
 }
 
-/// The over-under type is used to indicate whether the tips of curved lines such as slurs and ties are overhand (tips down) or underhand (tips up).
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum OverUnder {
-	Over,
-	Under,
+impl fmt::Display for CommaSeparatedText {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(&self.text)
+	}
 }
 
-/// The pitched type represents pictograms for pitched percussion instruments. The chimes and tubular chimes values distinguish the single-line and double-line versions of the pictogram. The mallet value is in addition to Stone's list.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum Pitched {
-	Chimes,
-	Glockenspiel,
-	Mallet,
-	Marimba,
-	#[serde(rename = "tubular chimes")]
-	TubularChimes,
-	Vibraphone,
-	Xylophone,
+impl Serialize for CommaSeparatedText {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&self.text)
+	}
 }
 
-/// The positive-integer-or-empty values can be either a positive integer or an empty string.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(untagged)]
-pub enum PositiveIntegerOrEmpty {
-	Integer(u64),
-	Empty(String),
+impl<'de> Deserialize<'de> for CommaSeparatedText {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_from_str(deserializer)
+	}
 }
 
-/// The principal-voice-symbol type represents the type of symbol used to indicate the start of a principal or secondary voice. The "plain" value represents a plain square bracket. The value of "none" is used for analysis markup when the principal-voice element does not have a corresponding appearance in the score.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum PrincipalVoceSymbol {
-	#[serde(rename = "Hauptstimme")]
-	Hauptstimme,
-	#[serde(rename = "Nebenstimme")]
-	Nebenstimme,
-	Plain,
-	None,
-}
-
-/// The right-left-middle type is used to specify barline location.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum RightLeftMiddle {
-	Right,
-	Left,
-	Middle,
-}
-
-/// The semi-pitched type represents categories of indefinite pitch for percussion instruments.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "kebab-case")]
-pub enum SemiPitched {
-	High,
-	MediumHigh,
-	Medium,
-	MediumLow,
-	Low,
-	VeryLow,
-}
-
-/// The show-frets type indicates whether to show tablature frets as numbers (0, 1, 2) or letters (a, b, c). The default choice is numbers.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum ShowFrets {
-	Numbers,
-	Letters,
-}
-
-/// The show-tuplet type indicates whether to show a part of a tuplet relating to the tuplet-actual element, both the tuplet-actual and tuplet-normal elements, or neither.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum ShowTuplet {
-	Actual,
-	Both,
-	None,
-}
-
-/// The staff-type value can be ossia, cue, editorial, regular, or alternate. An alternate staff indicates one that shares the same musical data as the prior staff, but displayed differently (e.g., treble and bass clef, standard notation and tab).
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum StaffType {
-	Ossia,
-	Cue,
-	Editorial,
-	Regular,
-	Alternate,
-}
-
-/// The start-note type describes the starting note of trills and mordents for playback, relative to the current note.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum StartNote {
-	Upper,
-	Main,
-	Below,
-}
-
-/// The start-stop type is used for an attribute of musical elements that can either start or stop, such as tuplets.  The values of start and stop refer to how an element appears in musical score order, not in MusicXML document order. An element with a stop attribute may precede the corresponding element with a start attribute within a MusicXML document. This is particularly common in multi-staff music. For example, the stopping point for a tuplet may appear in staff 1 before the starting point for the tuplet appears in staff 2 later in the document.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum StartStop {
-	Start,
-	Stop,
-}
-
-/// The start-stop-change-continue type is used to distinguish types of pedal directions.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum StartStopChangeContinue {
-	Start,
-	Stop,
-	Change,
-	Continue,
-}
-
-/// The start-stop-continue type is used for an attribute of musical elements that can either start or stop, but also need to refer to an intermediate point in the symbol, as for complex slurs or for formatting of symbols across system breaks.  The values of start, stop, and continue refer to how an element appears in musical score order, not in MusicXML document order. An element with a stop attribute may precede the corresponding element with a start attribute within a MusicXML document. This is particularly common in multi-staff music. For example, the stopping point for a slur may appear in staff 1 before the starting point for the slur appears in staff 2 later in the document.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum StartStopContinue {
-	Start,
-	Stop,
-	Continue,
-}
-
-/// The start-stop-discontinue type is used to specify ending types. Typically, the start type is associated with the left barline of the first measure in an ending. The stop and discontinue types are associated with the right barline of the last measure in an ending. Stop is used when the ending mark concludes with a downward jog, as is typical for first endings. Discontinue is used when there is no downward jog, as is typical for second endings that do not conclude a piece.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum StartStopDiscontinue {
-	Start,
-	Stop,
-	Discontinue,
-}
-
-/// The start-stop-single type is used for an attribute of musical elements that can be used for either multi-note or single-note musical elements, as for tremolos.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum StartStopSingle {
-	Start,
-	Stop,
-	Single,
-}
-
-/// The stem type represents the notated stem direction.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum StemValue {
-	Down,
-	Up,
-	Double,
-	None,
-}
-
-/// The step type represents a step of the diatonic scale, represented using the English letters A through G.
-#[derive(Debug, Deserialize, PartialEq)]
-pub enum Step {
-	A,
-	B,
-	C,
-	D,
-	E,
-	F,
-	G,
-}
-
-/// The stick-location type represents pictograms for the location of sticks, beaters, or mallets on cymbals, gongs, drums, and other instruments.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum StickLocation {
-	Center,
-	Rim,
-	#[serde(rename = "cymbal bell")]
-	CymballBell,
-	#[serde(rename = "cymbal edge")]
-	CymbalEdge,
-}
-
-/// The stick-material type represents the material being displayed in a stick pictogram.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum StickMaterial {
-	Soft,
-	Medium,
-	Hard,
-	Shaded,
-	X,
-}
-
-/// The stick-type type represents the shape of pictograms where the material in the stick, mallet, or beater is represented in the pictogram.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum StickType {
-	#[serde(rename = "bass drum")]
-	BassDrum,
-	#[serde(rename = "double bass drum")]
-	DoubleBassDrum,
-	Timpani,
-	Xylophone,
-	Yarn,
-}
-
-/// Lyric hyphenation is indicated by the syllabic type. The single, begin, end, and middle values represent single-syllable words, word-beginning syllables, word-ending syllables, and mid-word syllables, respectively.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum Syllabic {
-	Single,
-	Begin,
-	End,
-	Middle,
-}
-
-/// The symbol-size type is used to indicate full vs. cue-sized vs. oversized symbols. The large value for oversized symbols was added in version 1.1.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum SymbolSize {
-	Full,
-	Cue,
-	Large,
-}
-
-/// The text-direction type is used to adjust and override the Unicode bidirectional text algorithm, similar to the W3C Internationalization Tag Set recommendation. Values are ltr (left-to-right embed), rtl (right-to-left embed), lro (left-to-right bidi-override), and rlo (right-to-left bidi-override). The default value is ltr. This type is typically used by applications that store text in left-to-right visual order rather than logical order. Such applications can use the lro value to better communicate with other applications that more fully support bidirectional text.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum TextDirection {
-	Ltr,
-	Rtl,
-	Lro,
-	Rlo,
-}
-
-/// The time-relation type indicates the symbol used to represent the interchangeable aspect of dual time signatures.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum TimeRelation {
-	Parentheses,
-	Bracket,
-	Equals,
-	Slash,
-	Space,
-	Hyphen,
-}
-
-/// The time-separator type indicates how to display the arrangement between the beats and beat-type values in a time signature. The default value is none. The horizontal, diagonal, and vertical values represent horizontal, diagonal lower-left to upper-right, and vertical lines respectively. For these values, the beats and beat-type values are arranged on either side of the separator line. The none value represents no separator with the beats and beat-type arranged vertically. The adjacent value represents no separator with the beats and beat-type arranged horizontally.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum TimeSeparator {
-	None,
-	Horizontal,
-	Diagonal,
-	Vertical,
-	Adjacent,
-}
-
-/// The time-symbol type indicates how to display a time signature. The normal value is the usual fractional display, and is the implied symbol type if none is specified. Other options are the common and cut time symbols, as well as a single number with an implied denominator. The note symbol indicates that the beat-type should be represented with the corresponding downstem note rather than a number. The dotted-note symbol indicates that the beat-type should be represented with a dotted downstem note that corresponds to three times the beat-type value, and a numerator that is one third the beats value.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "kebab-case")]
-pub enum TimeSymbol {
-	Common,
-	Cut,
-	SingleNumber,
-	Note,
-	DottedNote,
-	Normal,
-}
-
-/// The tip-direction type represents the direction in which the tip of a stick or beater points, using Unicode arrow terminology.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum TipDirection {
-	Up,
-	Down,
-	Left,
-	Right,
-	Northwest,
-	Northeast,
-	Southeast,
-	Southwest,
-}
-
-/// The top-bottom type is used to indicate the top or bottom part of a vertical shape like non-arpeggiate.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum TopBottom {
-	Top,
-	Bottom,
-}
-
-/// The trill-step type describes the alternating note of trills and mordents for playback, relative to the current note.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum TrillStep {
-	Whole,
-	Half,
-	Unison,
-}
-
-/// The two-note-turn type describes the ending notes of trills and mordents for playback, relative to the current note.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum TwoNoteTurn {
-	Whole,
-	Half,
-	None,
-}
-
-/// The up-down type is used for the direction of arrows and other pointed symbols like vertical accents, indicating which way the tip is pointing.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum UpDown {
-	Up,
-	Down,
-}
-
-/// The up-down-stop-continue type is used for octave-shift elements, indicating the direction of the shift from their true pitched values because of printing difficulty.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum UpDownStopContinue {
-	Up,
-	Down,
-	Stop,
-	Continue,
-}
-
-/// The upright-inverted type describes the appearance of a fermata element. The value is upright if not specified.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum UprightInverted {
-	Upright,
-	Inverted,
-}
-
-/// The valign type is used to indicate vertical alignment to the top, middle, bottom, or baseline of the text. Defaults are implementation-dependent.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum Valign {
-	Top,
-	Middle,
-	Bottom,
-	Baseline,
-}
-
-/// The valign-image type is used to indicate vertical alignment for images and graphics, so it does not include a baseline value. Defaults are implementation-dependent.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum ValignImage {
-	Top,
-	Middle,
-	Bottom,
-}
-
-/// The wedge type is crescendo for the start of a wedge that is closed at the left side, diminuendo for the start of a wedge that is closed on the right side, and stop for the end of a wedge. The continue type is used for formatting wedges over a system break, or for other situations where a single wedge is divided into multiple segments.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum WedgeType {
-	Crescendo,
-	Diminuendo,
-	Stop,
-	Continue,
-}
-
-/// The winged attribute indicates whether the repeat has winged extensions that appear above and below the barline. The straight and curved values represent single wings, while the double-straight and double-curved values represent double wings. The none value indicates no wings and is the default.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "kebab-case")]
-pub enum Winged {
-	None,
-	Straight,
-	Curved,
-	DoubleStraight,
-	DoubleCurved,
-}
-
-/// The wood type represents pictograms for wood percussion instruments. The maraca and maracas values distinguish the one- and two-maraca versions of the pictogram. The vibraslap and castanets values are in addition to Stone's list.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum Wood {
-	#[serde(rename = "board clapper")]
-	BoardClapper,
-	Cabasa,
-	Castanets,
-	Claves,
-	Guiro,
-	#[serde(rename = "log drum")]
-	LogDrum,
-	Maraca,
-	Maracas,
-	Ratchet,
-	#[serde(rename = "sandpaper blocks")]
-	SandpaperBlocks,
-	#[serde(rename = "slit drum")]
-	SlitDrum,
-	#[serde(rename = "temple block")]
-	TempleBlock,
-	Vibraslap,
-	#[serde(rename = "wood block")]
-	WoodBlock,
-}
-
-/// The yes-no type is used for boolean-like attributes. We cannot use W3C XML Schema booleans due to their restrictions on expression of boolean values.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum YesNo {
-	Yes,
-	No,
+/// The ending-number type is used to specify either a comma-separated list of positive integers without leading zeros, or a string of zero or more spaces. It is used for the number attribute of the ending element. The zero or more spaces version is used when software knows that an ending is present, but cannot determine the type of the ending.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndingNumber {
+	text: String,
+	numbers: Vec<u32>,
 }
 
-/// The yes-no-number type is used for attributes that can be either boolean or numeric values.
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(untagged)]
-pub enum YesNoNumber {
-	Boolean(YesNo),
+impl EndingNumber {
+	/// Returns the ending numbers. The list is empty when the number was given as zero or more spaces.
+	pub fn numbers(&self) -> &[u32] {
+		&self.numbers
+	}
+}
+
+impl FromStr for EndingNumber {
+	type Err = ValidationError;
+
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		validate_ending_number(text)?;
+		let numbers = if text.trim().is_empty() {
+			Vec::new()
+		} else {
+			parse_positive_integers(text).ok_or_else(|| ValidationError::new("Invalid ending number"))?
+		};
+		Ok(EndingNumber {
+			text: text.to_string(),
+			numbers,
+		})
+	}
+}
+
+impl fmt::Display for EndingNumber {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(&self.text)
+	}
+}
+
+impl Serialize for EndingNumber {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&self.text)
+	}
+}
+
+impl<'de> Deserialize<'de> for EndingNumber {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_from_str(deserializer)
+	}
+}
+
+/// The time-only type is used to indicate that a particular playback-related element only applies particular times through a repeated section. The value is a comma-separated list of positive integers arranged in ascending order, indicating which times through the repeated section that the element applies.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeOnly {
+	text: String,
+	times: Vec<u32>,
+}
+
+impl TimeOnly {
+	/// Returns the times through the repeated section that the element applies to.
+	pub fn times(&self) -> &[u32] {
+		&self.times
+	}
+}
+
+impl FromStr for TimeOnly {
+	type Err = ValidationError;
+
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		validate_time_only(text)?;
+		Ok(TimeOnly {
+			text: text.to_string(),
+			times: parse_positive_integers(text).ok_or_else(|| ValidationError::new("Invalid time only"))?,
+		})
+	}
+}
+
+impl fmt::Display for TimeOnly {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(&self.text)
+	}
+}
+
+impl Serialize for TimeOnly {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&self.text)
+	}
+}
+
+impl<'de> Deserialize<'de> for TimeOnly {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_from_str(deserializer)
+	}
+}
+
+/// A calendar date in the proleptic Gregorian calendar, as used by the yyyy-mm-dd type and the encoding-date element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+	pub year: i32,
+	pub month: u8,
+	pub day: u8,
+}
+
+impl Date {
+	/// Creates a date, returning None if the month or day do not exist in the calendar.
+	pub fn new(year: i32, month: u8, day: u8) -> Option<Date> {
+		if !(1..=12).contains(&month) || day < 1 || day > Date::days_in_month(year, month) {
+			return None;
+		}
+		Some(Date { year, month, day })
+	}
+
+	fn days_in_month(year: i32, month: u8) -> u8 {
+		match month {
+			2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+			2 => 28,
+			4 | 6 | 9 | 11 => 30,
+			_ => 31,
+		}
+	}
+}
+
+impl FromStr for Date {
+	type Err = ValidationError;
+
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		validate_yyyy_mm_dd(text)?;
+		let invalid = || ValidationError::new("Invalid date");
+		let (year, rest) = text.split_at(text.len() - 6);
+		let year = year.parse().map_err(|_| invalid())?;
+		let month = rest[1..3].parse().map_err(|_| invalid())?;
+		let day = rest[4..6].parse().map_err(|_| invalid())?;
+		Date::new(year, month, day).ok_or_else(invalid)
+	}
+}
+
+impl fmt::Display for Date {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.year < 0 {
+			write!(f, "-{:04}-{:02}-{:02}", -(self.year as i64), self.month, self.day)
+		} else {
+			write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+		}
+	}
+}
+
+impl Serialize for Date {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+impl<'de> Deserialize<'de> for Date {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_from_str(deserializer)
+	}
+}
+
+/// The smufl-glyph-name type is used for attributes that reference a specific Standard Music Font Layout (SMuFL) character. The value is a SMuFL canonical glyph name, not a code point. For instance, the value for a standard piano pedal mark would be keyboardPedalPed, not U+E650.
+///
+/// The more specific SMuFL glyph name types of the schema (accidental, coda, lyrics, pictogram, segno, and wavy-line glyph names) are represented by this type as well; the prefix methods can be used to check them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SmuflGlyphName {
+	name: String,
+}
+
+impl SmuflGlyphName {
+	/// Returns the canonical glyph name.
+	pub fn as_str(&self) -> &str {
+		&self.name
+	}
+
+	/// Returns whether the name is valid for the smufl-accidental-glyph-name type, which allows glyphs starting with acc, medRenFla, medRenNatura, medRenShar, or kievanAccidental.
+	pub fn is_accidental(&self) -> bool {
+		["acc", "medRenFla", "medRenNatura", "medRenShar", "kievanAccidental"]
+			.iter()
+			.any(|prefix| self.name.len() > prefix.len() && self.name.starts_with(prefix))
+	}
+
+	/// Returns whether the name is valid for the smufl-coda-glyph-name type, which allows glyphs starting with coda.
+	pub fn is_coda(&self) -> bool {
+		self.name.starts_with("coda")
+	}
+
+	/// Returns whether the name is valid for the smufl-segno-glyph-name type, which allows glyphs starting with segno.
+	pub fn is_segno(&self) -> bool {
+		self.name.starts_with("segno")
+	}
+
+	/// Returns whether the name is valid for the smufl-lyrics-glyph-name type, which allows glyphs starting with lyrics.
+	pub fn is_lyrics(&self) -> bool {
+		self.name.len() > "lyrics".len() && self.name.starts_with("lyrics")
+	}
+
+	/// Returns whether the name is valid for the smufl-pictogram-glyph-name type, which allows glyphs starting with pict.
+	pub fn is_pictogram(&self) -> bool {
+		self.name.len() > "pict".len() && self.name.starts_with("pict")
+	}
+
+	/// Returns whether the name is valid for the smufl-wavy-line-glyph-name type, which allows glyphs starting with wiggle as well as guitar vibrato strokes.
+	pub fn is_wavy_line(&self) -> bool {
+		(self.name.len() > "wiggle".len() && self.name.starts_with("wiggle"))
+			|| (self.name.starts_with("guitar") && self.name.ends_with("VibratoStroke"))
+	}
+}
+
+impl FromStr for SmuflGlyphName {
+	type Err = ValidationError;
+
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		validate_smufl_glyph_name(text)?;
+		Ok(SmuflGlyphName { name: text.to_string() })
+	}
+}
+
+impl fmt::Display for SmuflGlyphName {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(&self.name)
+	}
+}
+
+impl Serialize for SmuflGlyphName {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&self.name)
+	}
+}
+
+impl<'de> Deserialize<'de> for SmuflGlyphName {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_from_str(deserializer)
+	}
+}
+
+simple_enum! {
+	/// The above-below type is used to indicate whether one element appears above or below another element.
+	pub enum AboveBelow {
+		Above => "above",
+		Below => "below",
+	}
+}
+
+simple_enum! {
+	/// The accidental-value type represents notated accidentals supported by MusicXML. In the MusicXML 2.0 DTD this was a string with values that could be included. The XSD strengthens the data typing to an pub enumerated list. The quarter- and three-quarters- accidentals are Tartini-style quarter-tone accidentals. The -down and -up accidentals are quarter-tone accidentals that include arrows pointing down or up. The slash- accidentals are used in Turkish classical music. The numbered sharp and flat accidentals are superscripted versions of the accidental signs, used in Turkish folk music. The sori and koron accidentals are microtonal sharp and flat accidentals used in Iranian and Persian music.
+	pub enum AccidentalValue {
+		Sharp => "sharp",
+		Natural => "natural",
+		Flat => "flat",
+		DoubleSharp => "double-sharp",
+		SharpSharp => "sharp-sharp",
+		FlatFlat => "flat-flat",
+		NaturalSharp => "natural-sharp",
+		NaturalFlat => "natural-flat",
+		QuarterFlat => "quarter-flat",
+		ThreeQuartersFlat => "three-quarters-flat",
+		SharpDown => "sharp-down",
+		SharpUp => "sharp-up",
+		NaturalDown => "natural-down",
+		NaturalUp => "natural-up",
+		FlatDown => "flat-down",
+		FlatUp => "flat-up",
+		TripleSharp => "triple-sharp",
+		TripleFlat => "triple-flat",
+		SlashQuarterSharp => "slash-quarter-sharp",
+		SlashSharp => "slash-sharp",
+		SlashFlat => "slash-flat",
+		DoubleSlashFlat => "double-slash-flat",
+		Sharp1 => "sharp1",
+		Sharp2 => "sharp2",
+		Sharp3 => "sharp3",
+		Sharp5 => "sharp5",
+		Flat1 => "flat1",
+		Flat2 => "flat2",
+		Flat3 => "flat3",
+		Flat4 => "flat4",
+		Sori => "sori",
+		Koron => "koron",
+	}
+}
+
+simple_enum! {
+	/// The arrow-direction type represents the direction in which an arrow points, using Unicode arrow terminology.
+	pub enum ArrowDirection {
+		Left => "left",
+		Up => "up",
+		Right => "right",
+		Down => "down",
+		Northwest => "northwest",
+		Northeast => "northeast",
+		Southeast => "southeast",
+		Southwest => "southwest",
+		LeftRight => "left right",
+		UpDown => "up down",
+		NorthwestSoutheast => "northwest southeast",
+		NortheastSouthwest => "northeast southwest",
+		Other => "other",
+	}
+}
+
+simple_enum! {
+	/// The arrow-style type represents the style of an arrow, using Unicode arrow terminology. Filled and hollow arrows indicate polygonal single arrows. Paired arrows are duplicate single arrows in the same direction. Combined arrows apply to double direction arrows like left right, indicating that an arrow in one direction should be combined with an arrow in the other direction.
+	pub enum ArrowStyle {
+		Single => "single",
+		Double => "double",
+		Filled => "filled",
+		Hollow => "hollow",
+		Paired => "paired",
+		Combined => "combined",
+		Other => "other",
+	}
+}
+
+simple_enum! {
+	/// The backward-forward type is used to specify repeat directions. The start of the repeat has a forward direction while the end of the repeat has a backward direction.
+	pub enum BackwardForward {
+		Backward => "backward",
+		Forward => "forward",
+	}
+}
+
+simple_enum! {
+	/// The bar-style type represents barline style information. Choices are regular, dotted, dashed, heavy, light-light, light-heavy, heavy-light, heavy-heavy, tick (a short stroke through the top line), short (a partial barline between the 2nd and 4th lines), and none.
+	pub enum BarStyle {
+		Regular => "regular",
+		Dotted => "dotted",
+		Dashed => "dashed",
+		Heavy => "heavy",
+		LightLight => "light-light",
+		LightHeavy => "light-heavy",
+		HeavyLight => "heavy-light",
+		HeavyHeavy => "heavy-heavy",
+		Tick => "tick",
+		Short => "short",
+		None => "none",
+	}
+}
+
+simple_enum! {
+	/// The beam-value type represents the type of beam associated with each of 8 beam levels (up to 1024th notes) available for each note.
+	pub enum BeamValue {
+		Begin => "begin",
+		Continue => "continue",
+		End => "end",
+		ForwardHook => "forward hook",
+		BackwardHook => "backward hook",
+	}
+}
+
+simple_enum! {
+	/// The beater-value type represents pictograms for beaters, mallets, and sticks that do not have different materials represented in the pictogram. The finger and hammer values are in addition to Stone's list.
+	pub enum BeaterValue {
+		Bow => "bow",
+		ChimeHammer => "chime hammer",
+		Coin => "coin",
+		Finger => "finger",
+		Fingernail => "fingernail",
+		Fist => "fist",
+		GuiroScraper => "guiro scraper",
+		Hammer => "hammer",
+		Hand => "hand",
+		JazzStick => "jazz stick",
+		KnittingNeedle => "knitting needle",
+		MetalHammer => "metal hammer",
+		SnareStick => "snare stick",
+		SpoonMallet => "spoon mallet",
+		TriangleBeater => "triangle beater",
+		TriangleBeaterPlain => "triangle beater plain",
+		WireBrush => "wire brush",
+	}
+}
+
+simple_enum! {
+	/// The bend-shape type distinguishes between the angled bend symbols commonly used in standard notation and the curved bend symbols commonly used in both tablature and standard notation.
+	pub enum BendShape {
+		Angled => "angled",
+		Curved => "curved",
+	}
+}
+
+simple_enum! {
+	/// The breath-mark-value type represents the symbol used for a breath mark.
+	#[derive(Default)]
+	pub enum BreathMarkValue {
+		#[default]
+		Empty => "",
+		Comma => "comma",
+		Tick => "tick",
+		Upbow => "upbow",
+		Salzedo => "salzedo",
+	}
+}
+
+simple_enum! {
+	/// The caesura-value type indicates the shape of the caesura sign.
+	#[derive(Default)]
+	pub enum CaesuraValue {
+		Normal => "normal",
+		Thick => "thick",
+		Short => "short",
+		Curved => "curved",
+		Single => "single",
+		#[default]
+		Empty => "",
+	}
+}
+
+simple_enum! {
+	/// The cancel-location type is used to indicate where a key signature cancellation appears relative to a new key signature: to the left, to the right, or before the barline and to the left. It is left by default. For mid-measure key elements, a cancel-location of before-barline should be treated like a cancel-location of left.
+	#[derive(Default)]
+	pub enum CancelLocation {
+		#[default]
+		Left => "left",
+		Right => "right",
+		BeforeBarline => "before-barline",
+	}
+}
+
+simple_enum! {
+	/// The circular-arrow type represents the direction in which a circular arrow points, using Unicode arrow terminology.
+	pub enum CircularArrow {
+		Clockwise => "clockwise",
+		Anticlockwise => "anticlockwise",
+	}
+}
+
+simple_enum! {
+	/// The clef-sign element represents the different clef symbols. The jianpu sign indicates that the music that follows should be in jianpu numbered notation, just as the TAB sign indicates that the music that follows should be in tablature notation. Unlike TAB, a jianpu sign does not correspond to a visual clef notation.
+	pub enum ClefSign {
+		G => "G",
+		F => "F",
+		C => "C",
+		Percussion => "percussion",
+		TAB => "TAB",
+		Jianpu => "jianpu",
+		None => "none",
+	}
+}
+
+simple_enum! {
+	/// The css-font-size type includes the CSS font sizes used as an alternative to a numeric point size.
+	pub enum CssFontSize {
+		XxSmall => "xx-small",
+		XSmall => "x-small",
+		Small => "small",
+		Medium => "medium",
+		Large => "large",
+		XLarge => "x-large",
+		XXLarge => "xx-large",
+	}
+}
+
+simple_enum! {
+	/// The degree-symbol-value type indicates indicates that a symbol should be used in specifying the degree.
+	pub enum DegreeSymbolValue {
+		Major => "major",
+		Minor => "minor",
+		Augmented => "augmented",
+		Diminished => "diminished",
+		HalfDiminished => "half-diminished",
+	}
+}
+
+simple_enum! {
+	/// The degree-type-value type indicates whether the current degree element is an addition, alteration, or subtraction to the kind of the current chord in the harmony element.
+	pub enum DegreeTypeValue {
+		Add => "add",
+		Alter => "alter",
+		Subtract => "subtract",
+	}
+}
+
+simple_enum! {
+	/// The distance-type defines what type of distance is being defined in a distance element. Values include beam and hyphen. This is left as a string so that other application-specific types can be defined, but it is made a separate type so that it can be redefined more strictly.
+	pub enum DistanceType {
+		Beam => "beam",
+		Hyphen => "hyphen",
+		_ => Other(String),
+	}
+}
+
+simple_enum! {
+	/// The effect-value type represents pictograms for sound effect percussion instruments. The cannon value is in addition to Stone's list.
+	pub enum EffectValue {
+		Anvil => "anvil",
+		AutoHorn => "auto horn",
+		BirdWhistle => "bird whistle",
+		Cannon => "cannon",
+		DuckCall => "duck call",
+		GunShot => "gun shot",
+		KlaxonHorn => "klaxon horn",
+		LionsRoar => "lions roar",
+		PoliceWhistle => "police whistle",
+		Siren => "siren",
+		SlideWhistle => "slide whistle",
+		ThunderSheet => "thunder sheet",
+		WindMachine => "wind machine",
+		WindWhistle => "wind whistle",
+		_ => Other(String),
+	}
+}
+
+simple_enum! {
+	/// The enclosure-shape type describes the shape and presence / absence of an enclosure around text or symbols. A bracket enclosure is similar to a rectangle with the bottom line missing, as is common in jazz notation. An inverted-bracket enclosure is similar to a rectangle with the top line missing.
+	pub enum EnclosureShape {
+		Rectangle => "rectangle",
+		Square => "square",
+		Oval => "oval",
+		Circle => "circle",
+		Bracket => "bracket",
+		InvertedBracket => "inverted-bracket",
+		Triangle => "triangle",
+		Diamond => "diamond",
+		Pentagon => "pentagon",
+		Hexagon => "hexagon",
+		Heptagon => "heptagon",
+		Octagon => "octagon",
+		Nonagon => "nonagon",
+		Decagon => "decagon",
+		None => "none",
+	}
+}
+
+simple_enum! {
+	/// The fan type represents the type of beam fanning present on a note, used to represent accelerandos and ritardandos.
+	pub enum Fan {
+		Accel => "accel",
+		Rit => "rit",
+		None => "none",
+	}
+}
+
+simple_enum! {
+	/// The fermata-shape type represents the shape of the fermata sign. The empty value is equivalent to the normal value.
+	#[derive(Default)]
+	pub enum FermataShape {
+		Normal => "normal",
+		Angled => "angled",
+		Square => "square",
+		DoubleAngled => "double-angled",
+		DoubleSquare => "double-square",
+		DoubleDot => "double-dot",
+		HalfCurve => "half-curve",
+		Curlew => "curlew",
+		#[default]
+		Empty => "",
+	}
+}
+
+/// The font-size can be one of the CSS font sizes or a numeric point size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontSize {
+	Css(CssFontSize),
 	Decimal(f64),
 }
 
+impl FromStr for FontSize {
+	type Err = ValidationError;
+
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		match text.parse::<f64>() {
+			Ok(size) if size.is_finite() && size > 0.0 => Ok(FontSize::Decimal(size)),
+			Ok(_) => Err(ValidationError::new("Invalid font size")),
+			Err(_) => text.parse().map(FontSize::Css).map_err(|_| ValidationError::new("Invalid font size")),
+		}
+	}
+}
+
+impl fmt::Display for FontSize {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			FontSize::Css(size) => size.fmt(f),
+			FontSize::Decimal(size) => size.fmt(f),
+		}
+	}
+}
+
+impl Serialize for FontSize {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+impl<'de> Deserialize<'de> for FontSize {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_from_str(deserializer)
+	}
+}
+
+simple_enum! {
+	/// The font-style type represents a simplified version of the CSS font-style property.
+	pub enum FontStyle {
+		Normal => "normal",
+		Italic => "italic",
+	}
+}
+
+simple_enum! {
+	/// The font-weight type represents a simplified version of the CSS font-weight property.
+	pub enum FontWeight {
+		Normal => "normal",
+		Bold => "bold",
+	}
+}
+
+simple_enum! {
+	/// The glass-value type represents pictograms for glass percussion instruments.
+	pub enum GlassValue {
+		WindChimes => "wind chimes",
+	}
+}
+
+simple_enum! {
+	/// The group-barline-value type indicates if the group should have common barlines.
+	pub enum GroupBarlineValue {
+		Yes => "yes",
+		No => "no",
+		Mensurstrich => "Mensurstrich",
+	}
+}
+
+simple_enum! {
+	/// The group-symbol-value type indicates how the symbol for a group is indicated in the score. The default value is none.
+	#[derive(Default)]
+	pub enum GroupSymbolValue {
+		#[default]
+		None => "none",
+		Brace => "brace",
+		Line => "line",
+		Bracket => "bracket",
+		Square => "square",
+	}
+}
+
+simple_enum! {
+	/// The handbell-value type represents the type of handbell technique being notated.
+	pub enum HandbellValue {
+		Damp => "damp",
+		Echo => "echo",
+		Gyro => "gyro",
+		HandMartellato => "hand martellato",
+		MalletLift => "mallet lift",
+		MalletTable => "mallet table",
+		Martellato => "martellato",
+		MartellatoLift => "martellato lift",
+		MutedMartellato => "muted martellato",
+		PluckLift => "pluck lift",
+		Swing => "swing",
+	}
+}
+
+simple_enum! {
+	/// The harmon-closed-location type indicates which portion of the symbol is filled in when the corresponding harmon-closed-value is half.
+	pub enum HarmonClosedLocation {
+		Right => "right",
+		Bottom => "bottom",
+		Left => "left",
+		Top => "top",
+	}
+}
+
+simple_enum! {
+	/// The harmon-closed-value type represents whether the harmon mute is closed, open, or half-open.
+	pub enum HarmonClosedValue {
+		Yes => "yes",
+		No => "no",
+		Half => "half",
+	}
+}
+
+simple_enum! {
+	/// The harmony-type type differentiates different types of harmonies when alternate harmonies are possible. Explicit harmonies have all notes present in the music; implied have some notes missing but implied; alternate represents alternate analyses.
+	pub enum HarmonyType {
+		Explicit => "explicit",
+		Implied => "implied",
+		Alternate => "alternate",
+	}
+}
+
+simple_enum! {
+	/// The harmony-arrangement type indicates how stacked chords and bass notes are displayed within a harmony element. The vertical value specifies that the second element appears below the first. The horizontal value specifies that the second element appears to the right of the first. The diagonal value specifies that the second element appears both below and to the right of the first.
+	pub enum HarmonyArrangement {
+		Vertical => "vertical",
+		Horizontal => "horizontal",
+		Diagonal => "diagonal",
+	}
+}
+
+simple_enum! {
+	/// The hole-closed-location type indicates which portion of the hole is filled in when the corresponding hole-closed-value is half.
+	pub enum HoleClosedLocation {
+		Right => "right",
+		Bottom => "bottom",
+		Left => "left",
+		Top => "top",
+	}
+}
+
+simple_enum! {
+	/// The hole-closed-value type represents whether the hole is closed, open, or half-open.
+	pub enum HoleClosedValue {
+		Yes => "yes",
+		No => "no",
+		Half => "half",
+	}
+}
+
+simple_enum! {
+	/// A kind-value indicates the type of chord. Degree elements can then add, subtract, or alter from these starting points. Values include:  Triads: major (major third, perfect fifth) minor (minor third, perfect fifth) augmented (major third, augmented fifth) diminished (minor third, diminished fifth) Sevenths: dominant (major triad, minor seventh) major-seventh (major triad, major seventh) minor-seventh (minor triad, minor seventh) diminished-seventh (diminished triad, diminished seventh) augmented-seventh (augmented triad, minor seventh) half-diminished (diminished triad, minor seventh) major-minor (minor triad, major seventh) Sixths: major-sixth (major triad, added sixth) minor-sixth (minor triad, added sixth) Ninths: dominant-ninth (dominant-seventh, major ninth) major-ninth (major-seventh, major ninth) minor-ninth (minor-seventh, major ninth) 11ths (usually as the basis for alteration): dominant-11th (dominant-ninth, perfect 11th) major-11th (major-ninth, perfect 11th) minor-11th (minor-ninth, perfect 11th) 13ths (usually as the basis for alteration): dominant-13th (dominant-11th, major 13th) major-13th (major-11th, major 13th) minor-13th (minor-11th, major 13th) Suspended: suspended-second (major second, perfect fifth) suspended-fourth (perfect fourth, perfect fifth) Functional sixths: Neapolitan Italian French German Other: pedal (pedal-point bass) power (perfect fifth) Tristan  The "other" kind is used when the harmony is entirely composed of add elements. The "none" kind is used to explicitly encode absence of chords or functional harmony.
+	pub enum KindValue {
+		Major => "major",
+		Minor => "minor",
+		Augmented => "augmented",
+		Diminished => "diminished",
+		Dominant => "dominant",
+		MajorSeventh => "major-seventh",
+		MinorSeventh => "minor-seventh",
+		DiminishedSeventh => "diminished-seventh",
+		AugmentedSeventh => "augmented-seventh",
+		HalfDiminished => "half-diminished",
+		MajorMinor => "major-minor",
+		MajorSixth => "major-sixth",
+		MinorSixth => "minor-sixth",
+		DominantNinth => "dominant-ninth",
+		MajorNinth => "major-ninth",
+		MinorNinth => "minor-ninth",
+		Dominant11th => "dominant-11th",
+		Major11th => "major-11th",
+		Minor11th => "minor-11th",
+		Dominant13th => "dominant-13th",
+		Major13th => "major-13th",
+		Minor13th => "minor-13th",
+		SuspendedSecond => "suspended-second",
+		SuspendedFourth => "suspended-fourth",
+		Neapolitan => "Neapolitan",
+		Italian => "Italian",
+		French => "French",
+		German => "German",
+		Pedal => "pedal",
+		Power => "power",
+		Tristan => "Tristan",
+		Other => "other",
+		None => "none",
+	}
+}
+
+simple_enum! {
+	/// The left-center-right type is used to define horizontal alignment and text justification.
+	pub enum LeftCenterRight {
+		Left => "left",
+		Center => "center",
+		Right => "right",
+	}
+}
+
+simple_enum! {
+	/// The left-right type is used to indicate whether one element appears to the left or the right of another element.
+	pub enum LeftRight {
+		Left => "left",
+		Right => "right",
+	}
+}
+
+simple_enum! {
+	/// The line-end type specifies if there is a jog up or down (or both), an arrow, or nothing at the start or end of a bracket.
+	pub enum LineEnd {
+		Up => "up",
+		Down => "down",
+		Both => "both",
+		Arrow => "arrow",
+		None => "none",
+	}
+}
+
+simple_enum! {
+	/// The line-length type distinguishes between different line lengths for doit, falloff, plop, and scoop articulations.
+	pub enum LineLength {
+		Short => "short",
+		Medium => "medium",
+		Long => "long",
+	}
+}
+
+simple_enum! {
+	/// The line-shape type distinguishes between straight and curved lines.
+	pub enum LineShape {
+		Straight => "straight",
+		Curved => "curved",
+	}
+}
+
+simple_enum! {
+	/// The line-type type distinguishes between solid, dashed, dotted, and wavy lines.
+	pub enum LineType {
+		Solid => "solid",
+		Dashed => "dashed",
+		Dotted => "dotted",
+		Wavy => "wavy",
+	}
+}
+
+simple_enum! {
+	/// The line-width-type defines what type of line is being defined in a line-width element. Values include beam, bracket, dashes, enclosure, ending, extend, heavy barline, leger, light barline, octave shift, pedal, slur middle, slur tip, staff, stem, tie middle, tie tip, tuplet bracket, and wedge. This is left as a string so that other application-specific types can be defined, but it is made a separate type so that it can be redefined more strictly.
+	pub enum LineWidthType {
+		Beam => "beam",
+		Bracket => "bracket",
+		Dashes => "dashes",
+		Enclosure => "enclosure",
+		Ending => "ending",
+		Extend => "extend",
+		HeavyBarline => "heavy barline",
+		Leger => "leger",
+		LightBarline => "light barline",
+		OctaveShift => "octave shift",
+		Pedal => "pedal",
+		SlurMiddle => "slur middle",
+		SlurTip => "slur tip",
+		Staff => "staff",
+		Stem => "stem",
+		TieMiddle => "tie middle",
+		TieTip => "tie tip",
+		TupletBracket => "tuplet bracket",
+		Wedge => "wedge",
+		_ => Other(String),
+	}
+}
+
+simple_enum! {
+	/// The margin-type type specifies whether margins apply to even page, odd pages, or both.
+	pub enum MarginType {
+		Odd => "odd",
+		Even => "even",
+		Both => "both",
+	}
+}
+
+simple_enum! {
+	/// The measure-numbering-value type describes how measure numbers are displayed on this part: no numbers, numbers every measure, or numbers every system.
+	pub enum MeasureNumbering {
+		None => "none",
+		Measure => "measure",
+		System => "system",
+	}
+}
+
+simple_enum! {
+	/// The membrane-value type represents pictograms for membrane percussion instruments. The goblet drum value is in addition to Stone's list.
+	pub enum MembraneValue {
+		BassDrum => "bass drum",
+		BassDrumOnSide => "bass drum on side",
+		Bongos => "bongos",
+		CongaDrum => "conga drum",
+		GobletDrum => "goblet drum",
+		MilitaryDrum => "military drum",
+		SnareDrum => "snare drum",
+		SnareDrumSnaresOff => "snare drum snares off",
+		Tambourine => "tambourine",
+		TenorDrum => "tenor drum",
+		Timbales => "timbales",
+		Tomtom => "tomtom",
+	}
+}
+
+simple_enum! {
+	/// The metal-value type represents pictograms for metal percussion instruments. The hi-hat value refers to a pictogram like Stone's high-hat cymbals but without the long vertical line at the bottom.
+	pub enum MetalValue {
+		Almglocken => "almglocken",
+		Bell => "bell",
+		BellPlate => "bell plate",
+		BrakeDrum => "brake drum",
+		ChineseCymbal => "Chinese cymbal",
+		Cowbell => "cowbell",
+		CrashCymbals => "crash cymbals",
+		Crotale => "crotale",
+		CymbalTongs => "cymbal tongs",
+		DoomedGong => "doomed gong",
+		FingerCymbals => "finger cymbals",
+		Flexatone => "flexatone",
+		Gong => "gong",
+		HiHat => "hi-hat",
+		HighHatCymbals => "high-hat cymbals",
+		Handbell => "handbell",
+		Sistrum => "sistrum",
+		SizzleCymbal => "sizzle cymbal",
+		SleighBells => "sleigh bells",
+		SuspendedCymbal => "suspended cymbal",
+		TamTam => "tam tam",
+		Triangle => "triangle",
+		VietnameseHat => "Vietnamese hat",
+	}
+}
+
+simple_enum! {
+	/// The mode type is used to specify major/minor and other mode distinctions. Valid mode values include major, minor, dorian, phrygian, lydian, mixolydian, aeolian, ionian, locrian, and none.
+	pub enum Mode {
+		Major => "major",
+		Minor => "minor",
+		Dorian => "dorian",
+		Phrygian => "phrygian",
+		Lydian => "lydian",
+		Mixolydian => "mixolydian",
+		Aeolian => "aeolian",
+		Ionian => "ionian",
+		Locrian => "locrian",
+		None => "none",
+		_ => Other(String),
+	}
+}
+
+simple_enum! {
+	/// The mute type represents muting for different instruments, including brass, winds, and strings. The on and off values are used for undifferentiated mutes. The remaining values represent specific mutes.
+	pub enum Mute {
+		On => "on",
+		Off => "off",
+		Straight => "straight",
+		Cup => "cup",
+		HarmonNoStem => "harmon-no-stem",
+		HarmonStem => "harmon-stem",
+		Bucket => "bucket",
+		Plunger => "plunger",
+		Hat => "hat",
+		Solotone => "solotone",
+		Practice => "practice",
+		StopMute => "stop-mute",
+		StopHand => "stop-hand",
+		Echo => "echo",
+		Palm => "palm",
+	}
+}
+
+simple_enum! {
+	/// The notehead type indicates shapes other than the open and closed ovals associated with note durations. The values do, re, mi, fa, fa up, so, la, and ti correspond to Aikin's 7-shape system. The fa up shape is typically used with upstems; the fa shape is typically used with downstems or no stems.  The arrow shapes differ from triangle and inverted triangle by being centered on the stem. Slashed and back slashed notes include both the normal notehead and a slash. The triangle shape has the tip of the triangle pointing up; the inverted triangle shape has the tip of the triangle pointing down. The left triangle shape is a right triangle with the hypotenuse facing up and to the left.
+	pub enum NoteHeadValue {
+		Slash => "slash",
+		Triangle => "triangle",
+		Diamond => "diamond",
+		Square => "square",
+		Cross => "cross",
+		X => "x",
+		CircleX => "circle-x",
+		InvertedTriangle => "inverted triangle",
+		ArrowDown => "arrow down",
+		ArrowUp => "arrow up",
+		Slashed => "slashed",
+		BackSlashed => "back slashed",
+		Normal => "normal",
+		Cluster => "cluster",
+		CircleDot => "circle dot",
+		LeftTriangle => "left triangle",
+		Rectangle => "rectangle",
+		None => "none",
+		Do => "do",
+		Re => "re",
+		Mi => "mi",
+		Fa => "fa",
+		FaUp => "fa up",
+		So => "so",
+		La => "la",
+		Ti => "ti",
+	}
+}
+
+simple_enum! {
+	/// The note-size-type type indicates the type of note being defined by a note-size element. The grace type is used for notes of cue size that that include a grace element. The cue type is used for all other notes with cue size, whether defined explicitly or implicitly via a cue element. The large type is used for notes of large size.
+	pub enum NoteSizeType {
+		Cue => "cue",
+		Grace => "grace",
+		Large => "large",
+	}
+}
+
+simple_enum! {
+	/// The note-type type is used for the MusicXML type element and represents the graphic note type, from 1024th (shortest) to maxima (longest).
+	pub enum NoteTypeValue {
+		_1024th => "1024th",
+		_512th => "512th",
+		_256th => "256th",
+		_128th => "128th",
+		_64th => "64th",
+		_32nd => "32nd",
+		_16th => "16th",
+		Eighth => "eighth",
+		Quarter => "quarter",
+		Half => "half",
+		Whole => "whole",
+		Breve => "breve",
+		Long => "long",
+		Maxima => "maxima",
+	}
+}
+
+impl NoteTypeValue {
+	/// Returns the note type lasting one beat-type-th of a whole note, i.e. the note that stands for a time signature denominator. Only powers of two from 1 to 1024 have a corresponding note type.
+	pub fn from_beat_type(beat_type: u32) -> Option<NoteTypeValue> {
+		match beat_type {
+			1 => Some(NoteTypeValue::Whole),
+			2 => Some(NoteTypeValue::Half),
+			4 => Some(NoteTypeValue::Quarter),
+			8 => Some(NoteTypeValue::Eighth),
+			16 => Some(NoteTypeValue::_16th),
+			32 => Some(NoteTypeValue::_32nd),
+			64 => Some(NoteTypeValue::_64th),
+			128 => Some(NoteTypeValue::_128th),
+			256 => Some(NoteTypeValue::_256th),
+			512 => Some(NoteTypeValue::_512th),
+			1024 => Some(NoteTypeValue::_1024th),
+			_ => None,
+		}
+	}
+
+	/// Returns the time signature denominator this note type stands for. The breve, long, and maxima are longer than a whole note and have no beat-type value.
+	pub fn beat_type(&self) -> Option<u32> {
+		match self {
+			NoteTypeValue::_1024th => Some(1024),
+			NoteTypeValue::_512th => Some(512),
+			NoteTypeValue::_256th => Some(256),
+			NoteTypeValue::_128th => Some(128),
+			NoteTypeValue::_64th => Some(64),
+			NoteTypeValue::_32nd => Some(32),
+			NoteTypeValue::_16th => Some(16),
+			NoteTypeValue::Eighth => Some(8),
+			NoteTypeValue::Quarter => Some(4),
+			NoteTypeValue::Half => Some(2),
+			NoteTypeValue::Whole => Some(1),
+			NoteTypeValue::Breve | NoteTypeValue::Long | NoteTypeValue::Maxima => None,
+		}
+	}
+}
+
+/// The number-or-normal values can be either a decimal number or the string "normal". This is used by the line-height and letter-spacing attributes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumberOrNormal {
+	Number(f64),
+	Normal(String),
+}
+
+impl FromStr for NumberOrNormal {
+	type Err = ValidationError;
+
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		if text == "normal" {
+			return Ok(NumberOrNormal::Normal(text.to_string()));
+		}
+		text.parse().map(NumberOrNormal::Number).map_err(|_| ValidationError::new("Invalid number or normal"))
+	}
+}
+
+impl fmt::Display for NumberOrNormal {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			NumberOrNormal::Number(number) => number.fmt(f),
+			NumberOrNormal::Normal(text) => f.write_str(text),
+		}
+	}
+}
+
+impl Serialize for NumberOrNormal {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+impl<'de> Deserialize<'de> for NumberOrNormal {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_from_str(deserializer)
+	}
+}
+
+simple_enum! {
+	/// The numeral-mode type specifies the mode similar to the mode type, but with a restricted set of values. The different minor values are used to interpret numeral-root values of 6 and 7 when present in a minor key. The harmonic minor value sharpens the 7 and the melodic minor value sharpens both 6 and 7. If a minor mode is used without qualification, either in the mode or numeral-mode elements, natural minor is used.
+	pub enum NumeralMode {
+		Major => "major",
+		Minor => "minor",
+		NaturalMinor => "natural minor",
+		MelodicMinor => "melodic minor",
+		HarmonicMinor => "harmonic minor",
+	}
+}
+
+simple_enum! {
+	/// The on-off type is used for notation elements such as string mutes.
+	pub enum OnOff {
+		On => "on",
+		Off => "off",
+	}
+}
+
+simple_enum! {
+	/// The over-under type is used to indicate whether the tips of curved lines such as slurs and ties are overhand (tips down) or underhand (tips up).
+	pub enum OverUnder {
+		Over => "over",
+		Under => "under",
+	}
+}
+
+simple_enum! {
+	/// The pedal-type simple type is used to distinguish types of pedal directions. The start value indicates the start of a damper pedal, while the sostenuto value indicates the start of a sostenuto pedal. The other values can be used with either the damper or sostenuto pedal. The soft pedal is not included here because there is no special symbol or graphic used for it beyond what can be specified with words and bracket elements.
+	///
+	/// The change, continue, discontinue, and resume types are used when the line attribute is yes. The change type indicates a pedal lift and retake indicated with an inverted V marking. The continue type allows more precise formatting across system breaks and for more complex pedaling lines. The discontinue type indicates the end of a pedal line that does not include the explicit lift represented by the stop type. The resume type indicates the start of a pedal line that does not include the downstroke represented by the start type. It can be used when a line resumes after being discontinued, or to start a pedal line that is preceded by a text or symbol representation of the pedal.
+	pub enum PedalType {
+		Start => "start",
+		Stop => "stop",
+		Sostenuto => "sostenuto",
+		Change => "change",
+		Continue => "continue",
+		Discontinue => "discontinue",
+		Resume => "resume",
+	}
+}
+
+simple_enum! {
+	/// The pitched-value type represents pictograms for pitched percussion instruments. The chimes and tubular chimes values distinguish the single-line and double-line versions of the pictogram. The mallet value is in addition to Stone's list.
+	pub enum PitchedValue {
+		Chimes => "chimes",
+		Glockenspiel => "glockenspiel",
+		Mallet => "mallet",
+		Marimba => "marimba",
+		TubularChimes => "tubular chimes",
+		Vibraphone => "vibraphone",
+		Xylophone => "xylophone",
+	}
+}
+
+/// The positive-integer-or-empty values can be either a positive integer or an empty string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PositiveIntegerOrEmpty {
+	Integer(u64),
+	Empty(String),
+}
+
+impl FromStr for PositiveIntegerOrEmpty {
+	type Err = ValidationError;
+
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		if text.is_empty() {
+			return Ok(PositiveIntegerOrEmpty::Empty(String::new()));
+		}
+		text.parse().map(PositiveIntegerOrEmpty::Integer).map_err(|_| ValidationError::new("Invalid positive integer or empty"))
+	}
+}
+
+impl fmt::Display for PositiveIntegerOrEmpty {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			PositiveIntegerOrEmpty::Integer(integer) => integer.fmt(f),
+			PositiveIntegerOrEmpty::Empty(text) => f.write_str(text),
+		}
+	}
+}
+
+impl Serialize for PositiveIntegerOrEmpty {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+impl<'de> Deserialize<'de> for PositiveIntegerOrEmpty {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_from_str(deserializer)
+	}
+}
+
+simple_enum! {
+	/// The principal-voice-symbol type represents the type of symbol used to indicate the start of a principal or secondary voice. The "plain" value represents a plain square bracket. The value of "none" is used for analysis markup when the principal-voice element does not have a corresponding appearance in the score.
+	pub enum PrincipalVoceSymbol {
+		Hauptstimme => "Hauptstimme",
+		Nebenstimme => "Nebenstimme",
+		Plain => "plain",
+		None => "none",
+	}
+}
+
+simple_enum! {
+	/// The right-left-middle type is used to specify barline location.
+	pub enum RightLeftMiddle {
+		Right => "right",
+		Left => "left",
+		Middle => "middle",
+	}
+}
+
+simple_enum! {
+	/// The semi-pitched type represents categories of indefinite pitch for percussion instruments.
+	pub enum SemiPitched {
+		High => "high",
+		MediumHigh => "medium-high",
+		Medium => "medium",
+		MediumLow => "medium-low",
+		Low => "low",
+		VeryLow => "very-low",
+	}
+}
+
+simple_enum! {
+	/// The show-frets type indicates whether to show tablature frets as numbers (0, 1, 2) or letters (a, b, c). The default choice is numbers.
+	#[derive(Default)]
+	pub enum ShowFrets {
+		#[default]
+		Numbers => "numbers",
+		Letters => "letters",
+	}
+}
+
+simple_enum! {
+	/// The show-tuplet type indicates whether to show a part of a tuplet relating to the tuplet-actual element, both the tuplet-actual and tuplet-normal elements, or neither.
+	pub enum ShowTuplet {
+		Actual => "actual",
+		Both => "both",
+		None => "none",
+	}
+}
+
+simple_enum! {
+	/// The staff-divide-symbol type is used for staff division symbols. The down, up, and up-down values correspond to SMuFL code points U+E00B, U+E00C, and U+E00D respectively.
+	pub enum StaffDivideSymbol {
+		Down => "down",
+		Up => "up",
+		UpDown => "up-down",
+	}
+}
+
+simple_enum! {
+	/// The staff-type value can be ossia, cue, editorial, regular, or alternate. An alternate staff indicates one that shares the same musical data as the prior staff, but displayed differently (e.g., treble and bass clef, standard notation and tab).
+	pub enum StaffType {
+		Ossia => "ossia",
+		Cue => "cue",
+		Editorial => "editorial",
+		Regular => "regular",
+		Alternate => "alternate",
+	}
+}
+
+simple_enum! {
+	/// The start-note type describes the starting note of trills and mordents for playback, relative to the current note.
+	pub enum StartNote {
+		Upper => "upper",
+		Main => "main",
+		Below => "below",
+	}
+}
+
+simple_enum! {
+	/// The start-stop type is used for an attribute of musical elements that can either start or stop, such as tuplets.  The values of start and stop refer to how an element appears in musical score order, not in MusicXML document order. An element with a stop attribute may precede the corresponding element with a start attribute within a MusicXML document. This is particularly common in multi-staff music. For example, the stopping point for a tuplet may appear in staff 1 before the starting point for the tuplet appears in staff 2 later in the document.
+	pub enum StartStop {
+		Start => "start",
+		Stop => "stop",
+	}
+}
+
+simple_enum! {
+	/// The start-stop-change-continue type is used to distinguish types of pedal directions.
+	pub enum StartStopChangeContinue {
+		Start => "start",
+		Stop => "stop",
+		Change => "change",
+		Continue => "continue",
+	}
+}
+
+simple_enum! {
+	/// The start-stop-continue type is used for an attribute of musical elements that can either start or stop, but also need to refer to an intermediate point in the symbol, as for complex slurs or for formatting of symbols across system breaks.  The values of start, stop, and continue refer to how an element appears in musical score order, not in MusicXML document order. An element with a stop attribute may precede the corresponding element with a start attribute within a MusicXML document. This is particularly common in multi-staff music. For example, the stopping point for a slur may appear in staff 1 before the starting point for the slur appears in staff 2 later in the document.
+	pub enum StartStopContinue {
+		Start => "start",
+		Stop => "stop",
+		Continue => "continue",
+	}
+}
+
+simple_enum! {
+	/// The start-stop-discontinue type is used to specify ending types. Typically, the start type is associated with the left barline of the first measure in an ending. The stop and discontinue types are associated with the right barline of the last measure in an ending. Stop is used when the ending mark concludes with a downward jog, as is typical for first endings. Discontinue is used when there is no downward jog, as is typical for second endings that do not conclude a piece.
+	pub enum StartStopDiscontinue {
+		Start => "start",
+		Stop => "stop",
+		Discontinue => "discontinue",
+	}
+}
+
+simple_enum! {
+	/// The start-stop-single type is used for an attribute of musical elements that can be used for either multi-note or single-note musical elements, as for tremolos.
+	pub enum StartStopSingle {
+		Start => "start",
+		Stop => "stop",
+		Single => "single",
+	}
+}
+
+simple_enum! {
+	/// The stem type represents the notated stem direction.
+	pub enum StemValue {
+		Down => "down",
+		Up => "up",
+		Double => "double",
+		None => "none",
+	}
+}
+
+simple_enum! {
+	/// The step type represents a step of the diatonic scale, represented using the English letters A through G.
+	pub enum Step {
+		A => "A",
+		B => "B",
+		C => "C",
+		D => "D",
+		E => "E",
+		F => "F",
+		G => "G",
+	}
+}
+
+/// Steps in scale order starting from C, as used for octave numbering.
+const STEPS_FROM_C: [Step; 7] = [Step::C, Step::D, Step::E, Step::F, Step::G, Step::A, Step::B];
+
+impl Step {
+	/// Returns the position of the step within the octave, from 0 for C to 6 for B.
+	pub fn index(self) -> i64 {
+		STEPS_FROM_C.iter().position(|&step| step == self).unwrap() as i64
+	}
+
+	/// Returns the step at the given position within the octave, counting from C. Positions outside 0 to 6 wrap around.
+	pub fn from_index(index: i64) -> Step {
+		STEPS_FROM_C[index.rem_euclid(7) as usize]
+	}
+
+	/// Returns the number of semitones from C up to the natural step.
+	pub fn semitone(self) -> i64 {
+		[0, 2, 4, 5, 7, 9, 11][self.index() as usize]
+	}
+}
+
+simple_enum! {
+	/// The stick-location type represents pictograms for the location of sticks, beaters, or mallets on cymbals, gongs, drums, and other instruments.
+	pub enum StickLocation {
+		Center => "center",
+		Rim => "rim",
+		CymballBell => "cymbal bell",
+		CymbalEdge => "cymbal edge",
+	}
+}
+
+simple_enum! {
+	/// The stick-material type represents the material being displayed in a stick pictogram.
+	pub enum StickMaterial {
+		Soft => "soft",
+		Medium => "medium",
+		Hard => "hard",
+		Shaded => "shaded",
+		X => "x",
+	}
+}
+
+simple_enum! {
+	/// The stick-type type represents the shape of pictograms where the material in the stick, mallet, or beater is represented in the pictogram.
+	pub enum StickType {
+		BassDrum => "bass drum",
+		DoubleBassDrum => "double bass drum",
+		Timpani => "timpani",
+		Xylophone => "xylophone",
+		Yarn => "yarn",
+	}
+}
+
+simple_enum! {
+	/// Lyric hyphenation is indicated by the syllabic type. The single, begin, end, and middle values represent single-syllable words, word-beginning syllables, word-ending syllables, and mid-word syllables, respectively.
+	pub enum Syllabic {
+		Single => "single",
+		Begin => "begin",
+		End => "end",
+		Middle => "middle",
+	}
+}
+
+simple_enum! {
+	/// The sync-type type specifies the style that a score following application should use to synchronize an accompaniment with a performer. The none type indicates no synchronization to the performer. The tempo type indicates synchronization based on the performer tempo rather than individual events in the score. The event type indicates synchronization by following the performance of individual events in the score rather than the performer tempo. The mostly-tempo and mostly-event types combine these two approaches, with mostly-tempo giving more weight to tempo and mostly-event giving more weight to performed events. The always-event type provides the strictest synchronization by not being forgiving of missing performed events.
+	pub enum SyncType {
+		None => "none",
+		Tempo => "tempo",
+		MostlyTempo => "mostly-tempo",
+		MostlyEvent => "mostly-event",
+		Event => "event",
+		AlwaysEvent => "always-event",
+	}
+}
+
+simple_enum! {
+	/// The symbol-size type is used to indicate full vs. cue-sized vs. oversized symbols. The large value for oversized symbols was added in version 1.1.
+	pub enum SymbolSize {
+		Full => "full",
+		Cue => "cue",
+		Large => "large",
+	}
+}
+
+simple_enum! {
+	/// The system-relation type distinguishes elements that are associated with a system rather than the particular part where the element appears. A value of only-top indicates that the element should appear only on the top part of the current system. A value of also-top indicates that the element should appear on both the current part and the top part of the current system. If this value appears in a score, when parts are created the element should only appear once in this part, not twice. A value of none indicates that the element is associated only with the current part, not with the system.
+	pub enum SystemRelation {
+		OnlyTop => "only-top",
+		AlsoTop => "also-top",
+		None => "none",
+	}
+}
+
+simple_enum! {
+	/// The tap-hand type represents the symbol to use for a tap element. The left and right values refer to the SMuFL guitarLeftHandTapping and guitarRightHandTapping glyphs respectively.
+	pub enum TapHand {
+		Left => "left",
+		Right => "right",
+	}
+}
+
+simple_enum! {
+	/// The text-direction type is used to adjust and override the Unicode bidirectional text algorithm, similar to the W3C Internationalization Tag Set recommendation. Values are ltr (left-to-right embed), rtl (right-to-left embed), lro (left-to-right bidi-override), and rlo (right-to-left bidi-override). The default value is ltr. This type is typically used by applications that store text in left-to-right visual order rather than logical order. Such applications can use the lro value to better communicate with other applications that more fully support bidirectional text.
+	#[derive(Default)]
+	pub enum TextDirection {
+		#[default]
+		Ltr => "ltr",
+		Rtl => "rtl",
+		Lro => "lro",
+		Rlo => "rlo",
+	}
+}
+
+simple_enum! {
+	/// The time-relation type indicates the symbol used to represent the interchangeable aspect of dual time signatures.
+	pub enum TimeRelation {
+		Parentheses => "parentheses",
+		Bracket => "bracket",
+		Equals => "equals",
+		Slash => "slash",
+		Space => "space",
+		Hyphen => "hyphen",
+	}
+}
+
+simple_enum! {
+	/// The time-separator type indicates how to display the arrangement between the beats and beat-type values in a time signature. The default value is none. The horizontal, diagonal, and vertical values represent horizontal, diagonal lower-left to upper-right, and vertical lines respectively. For these values, the beats and beat-type values are arranged on either side of the separator line. The none value represents no separator with the beats and beat-type arranged vertically. The adjacent value represents no separator with the beats and beat-type arranged horizontally.
+	#[derive(Default)]
+	pub enum TimeSeparator {
+		#[default]
+		None => "none",
+		Horizontal => "horizontal",
+		Diagonal => "diagonal",
+		Vertical => "vertical",
+		Adjacent => "adjacent",
+	}
+}
+
+simple_enum! {
+	/// The time-symbol type indicates how to display a time signature. The normal value is the usual fractional display, and is the implied symbol type if none is specified. Other options are the common and cut time symbols, as well as a single number with an implied denominator. The note symbol indicates that the beat-type should be represented with the corresponding downstem note rather than a number. The dotted-note symbol indicates that the beat-type should be represented with a dotted downstem note that corresponds to three times the beat-type value, and a numerator that is one third the beats value.
+	#[derive(Default)]
+	pub enum TimeSymbol {
+		Common => "common",
+		Cut => "cut",
+		SingleNumber => "single-number",
+		Note => "note",
+		DottedNote => "dotted-note",
+		#[default]
+		Normal => "normal",
+	}
+}
+
+impl TimeSymbol {
+	/// Converts numeric beats and beat-type values into the form displayed by the note and dotted-note symbols. Returns None for the other symbols, or if the values cannot be displayed that way (e.g. a dotted-note symbol on beats that are not a multiple of three).
+	pub fn note_form(&self, beats: u32, beat_type: u32) -> Option<NoteTimeSignature> {
+		match self {
+			TimeSymbol::Note => Some(NoteTimeSignature {
+				beats,
+				note: NoteTypeValue::from_beat_type(beat_type)?,
+				dotted: false,
+			}),
+			TimeSymbol::DottedNote => {
+				if !beats.is_multiple_of(3) || !beat_type.is_multiple_of(2) {
+					return None;
+				}
+				Some(NoteTimeSignature {
+					beats: beats / 3,
+					note: NoteTypeValue::from_beat_type(beat_type / 2)?,
+					dotted: true,
+				})
+			}
+			_ => None,
+		}
+	}
+}
+
+/// A time signature as displayed with the note or dotted-note time symbol: a numerator over a downstem note, possibly dotted, instead of over a number.
+#[derive(Debug, PartialEq)]
+pub struct NoteTimeSignature {
+	pub beats: u32,
+	pub note: NoteTypeValue,
+	pub dotted: bool,
+}
+
+impl NoteTimeSignature {
+	/// Converts the displayed form back into numeric beats and beat-type values. Returns None if the note is longer than a whole note or the beats overflow.
+	pub fn numeric(&self) -> Option<(u32, u32)> {
+		let beat_type = self.note.beat_type()?;
+		if self.dotted {
+			Some((self.beats.checked_mul(3)?, beat_type * 2))
+		} else {
+			Some((self.beats, beat_type))
+		}
+	}
+}
+
+simple_enum! {
+	/// The tip-direction type represents the direction in which the tip of a stick or beater points, using Unicode arrow terminology.
+	pub enum TipDirection {
+		Up => "up",
+		Down => "down",
+		Left => "left",
+		Right => "right",
+		Northwest => "northwest",
+		Northeast => "northeast",
+		Southeast => "southeast",
+		Southwest => "southwest",
+	}
+}
+
+simple_enum! {
+	/// The top-bottom type is used to indicate the top or bottom part of a vertical shape like non-arpeggiate.
+	pub enum TopBottom {
+		Top => "top",
+		Bottom => "bottom",
+	}
+}
+
+simple_enum! {
+	/// The tremolo-type is used to distinguish double-note, single-note, and unmeasured tremolos. It is single by default.
+	#[derive(Default)]
+	pub enum TremoloType {
+		Start => "start",
+		Stop => "stop",
+		#[default]
+		Single => "single",
+		Unmeasured => "unmeasured",
+	}
+}
+
+simple_enum! {
+	/// The trill-step type describes the alternating note of trills and mordents for playback, relative to the current note.
+	pub enum TrillStep {
+		Whole => "whole",
+		Half => "half",
+		Unison => "unison",
+	}
+}
+
+simple_enum! {
+	/// The two-note-turn type describes the ending notes of trills and mordents for playback, relative to the current note.
+	pub enum TwoNoteTurn {
+		Whole => "whole",
+		Half => "half",
+		None => "none",
+	}
+}
+
+simple_enum! {
+	/// The up-down type is used for the direction of arrows and other pointed symbols like vertical accents, indicating which way the tip is pointing.
+	pub enum UpDown {
+		Up => "up",
+		Down => "down",
+	}
+}
+
+simple_enum! {
+	/// The up-down-stop-continue type is used for octave-shift elements, indicating the direction of the shift from their true pitched values because of printing difficulty.
+	pub enum UpDownStopContinue {
+		Up => "up",
+		Down => "down",
+		Stop => "stop",
+		Continue => "continue",
+	}
+}
+
+simple_enum! {
+	/// The upright-inverted type describes the appearance of a fermata element. The value is upright if not specified.
+	#[derive(Default)]
+	pub enum UprightInverted {
+		#[default]
+		Upright => "upright",
+		Inverted => "inverted",
+	}
+}
+
+simple_enum! {
+	/// The valign type is used to indicate vertical alignment to the top, middle, bottom, or baseline of the text. Defaults are implementation-dependent.
+	pub enum Valign {
+		Top => "top",
+		Middle => "middle",
+		Bottom => "bottom",
+		Baseline => "baseline",
+	}
+}
+
+simple_enum! {
+	/// The valign-image type is used to indicate vertical alignment for images and graphics, so it does not include a baseline value. Defaults are implementation-dependent.
+	pub enum ValignImage {
+		Top => "top",
+		Middle => "middle",
+		Bottom => "bottom",
+	}
+}
+
+simple_enum! {
+	/// The wedge type is crescendo for the start of a wedge that is closed at the left side, diminuendo for the start of a wedge that is closed on the right side, and stop for the end of a wedge. The continue type is used for formatting wedges over a system break, or for other situations where a single wedge is divided into multiple segments.
+	pub enum WedgeType {
+		Crescendo => "crescendo",
+		Diminuendo => "diminuendo",
+		Stop => "stop",
+		Continue => "continue",
+	}
+}
+
+simple_enum! {
+	/// The winged attribute indicates whether the repeat has winged extensions that appear above and below the barline. The straight and curved values represent single wings, while the double-straight and double-curved values represent double wings. The none value indicates no wings and is the default.
+	#[derive(Default)]
+	pub enum Winged {
+		#[default]
+		None => "none",
+		Straight => "straight",
+		Curved => "curved",
+		DoubleStraight => "double-straight",
+		DoubleCurved => "double-curved",
+	}
+}
+
+simple_enum! {
+	/// The wood-value type represents pictograms for wood percussion instruments. The maraca and maracas values distinguish the one- and two-maraca versions of the pictogram. The vibraslap and castanets values are in addition to Stone's list.
+	pub enum WoodValue {
+		BoardClapper => "board clapper",
+		Cabasa => "cabasa",
+		Castanets => "castanets",
+		Claves => "claves",
+		Guiro => "guiro",
+		LogDrum => "log drum",
+		Maraca => "maraca",
+		Maracas => "maracas",
+		Ratchet => "ratchet",
+		SandpaperBlocks => "sandpaper blocks",
+		SlitDrum => "slit drum",
+		TempleBlock => "temple block",
+		Vibraslap => "vibraslap",
+		WoodBlock => "wood block",
+	}
+}
+
+simple_enum! {
+	/// The xlink:actuate attribute indicates when the link is traversed: automatically on load, or on request by the user.
+	#[derive(Default)]
+	pub enum XlinkActuate {
+		#[default]
+		OnRequest => "onRequest",
+		OnLoad => "onLoad",
+		Other => "other",
+		None => "none",
+	}
+}
+
+simple_enum! {
+	/// The xlink:show attribute indicates how the linked resource is presented: in a new window, replacing the current document, or embedded in it.
+	#[derive(Default)]
+	pub enum XlinkShow {
+		New => "new",
+		#[default]
+		Replace => "replace",
+		Embed => "embed",
+		Other => "other",
+		None => "none",
+	}
+}
+
+simple_enum! {
+	/// The xml:space attribute indicates whether whitespace in the text content is significant. With the default value, whitespace is collapsed as in ordinary XML text; with preserve, it is kept as written.
+	#[derive(Default)]
+	pub enum XmlSpace {
+		#[default]
+		Default => "default",
+		Preserve => "preserve",
+	}
+}
+
+simple_enum! {
+	/// The yes-no type is used for boolean-like attributes. We cannot use W3C XML Schema booleans due to their restrictions on expression of boolean values.
+	pub enum YesNo {
+		Yes => "yes",
+		No => "no",
+	}
+}
+
+/// The yes-no-number type is used for attributes that can be either boolean or numeric values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum YesNoNumber {
+	Boolean(YesNo),
+	Decimal(f64),
+}
+
+impl FromStr for YesNoNumber {
+	type Err = ValidationError;
+
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		if let Ok(value) = text.parse() {
+			return Ok(YesNoNumber::Boolean(value));
+		}
+		text.parse().map(YesNoNumber::Decimal).map_err(|_| ValidationError::new("Invalid yes no number"))
+	}
+}
+
+impl fmt::Display for YesNoNumber {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			YesNoNumber::Boolean(value) => value.fmt(f),
+			YesNoNumber::Decimal(value) => value.fmt(f),
+		}
+	}
+}
+
+impl Serialize for YesNoNumber {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+impl<'de> Deserialize<'de> for YesNoNumber {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_from_str(deserializer)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn time_symbol_note_form() {
+		let dotted = TimeSymbol::DottedNote.note_form(6, 8).unwrap();
+		assert_eq!(dotted, NoteTimeSignature { beats: 2, note: NoteTypeValue::Quarter, dotted: true });
+		assert_eq!(dotted.numeric(), Some((6, 8)));
+		assert_eq!(TimeSymbol::DottedNote.note_form(4, 8), None);
+		let note = TimeSymbol::Note.note_form(3, 2).unwrap();
+		assert_eq!(note, NoteTimeSignature { beats: 3, note: NoteTypeValue::Half, dotted: false });
+		assert_eq!(note.numeric(), Some((3, 2)));
+		assert_eq!(TimeSymbol::Note.note_form(3, 3), None);
+		assert_eq!(TimeSymbol::Common.note_form(4, 4), None);
+		assert_eq!(NoteTypeValue::from_beat_type(4), Some(NoteTypeValue::Quarter));
+		assert_eq!(NoteTypeValue::Quarter.beat_type(), Some(4));
+	}
+
+	#[test]
+	fn ending_number() {
+		let ending: EndingNumber = "1, 2".parse().unwrap();
+		assert_eq!(ending.numbers(), [1, 2]);
+		assert_eq!(ending.to_string(), "1, 2");
+		let unknown: EndingNumber = "  ".parse().unwrap();
+		assert!(unknown.numbers().is_empty());
+		assert!("01".parse::<EndingNumber>().is_err());
+		assert!("1,".parse::<EndingNumber>().is_err());
+	}
+
+	#[test]
+	fn time_only_and_comma_separated_text() {
+		let time_only: TimeOnly = "1,3".parse().unwrap();
+		assert_eq!(time_only.times(), [1, 3]);
+		assert_eq!(time_only.to_string(), "1,3");
+		assert!("".parse::<TimeOnly>().is_err());
+		assert!("0".parse::<TimeOnly>().is_err());
+		let fonts: CommaSeparatedText = "Maestro, serif".parse().unwrap();
+		assert_eq!(fonts.values(), ["Maestro".to_string(), "serif".to_string()]);
+		assert_eq!(fonts.to_string(), "Maestro, serif");
+		assert!(",serif".parse::<CommaSeparatedText>().is_err());
+	}
+
+	#[test]
+	fn date() {
+		let date: Date = "2020-02-29".parse().unwrap();
+		assert_eq!(date, Date { year: 2020, month: 2, day: 29 });
+		assert_eq!(date.to_string(), "2020-02-29");
+		assert!("2019-02-29".parse::<Date>().is_err());
+		assert!("1900-02-29".parse::<Date>().is_err());
+		assert!("2000-02-29".parse::<Date>().is_ok());
+		assert!("2020-13-01".parse::<Date>().is_err());
+		assert!("2020-01-01Z".parse::<Date>().is_err());
+		assert_eq!("-0044-03-15".parse::<Date>().unwrap().to_string(), "-0044-03-15");
+		assert_eq!(Date::new(2021, 4, 31), None);
+	}
+
+	#[test]
+	fn font_size() {
+		assert_eq!("12.5".parse::<FontSize>().ok(), Some(FontSize::Decimal(12.5)));
+		assert_eq!("x-large".parse::<FontSize>().ok(), Some(FontSize::Css(CssFontSize::XLarge)));
+		for text in &["NaN", "inf", "-inf", "0", "-3"] {
+			assert!(text.parse::<FontSize>().is_err(), "{} should be rejected", text);
+		}
+	}
+
+	#[test]
+	fn simple_enum_round_trip() {
+		for value in MembraneValue::VALUES {
+			assert_eq!(value.parse::<MembraneValue>().unwrap().as_str(), *value);
+		}
+		for value in MetalValue::VALUES {
+			assert_eq!(value.parse::<MetalValue>().unwrap().to_string(), *value);
+		}
+		assert_eq!("snare drum snares off".parse::<MembraneValue>().ok(), Some(MembraneValue::SnareDrumSnaresOff));
+		assert_eq!("high-hat cymbals".parse::<MetalValue>().ok(), Some(MetalValue::HighHatCymbals));
+		assert_eq!(MetalValue::HighHatCymbals.as_str(), "high-hat cymbals");
+		assert!("bass drum snares off".parse::<MembraneValue>().is_err());
+		assert_eq!(NoteTypeValue::_1024th.to_string(), "1024th");
+	}
+
+	#[test]
+	fn open_enum() {
+		let hyphen: DistanceType = "hyphen".parse().unwrap();
+		assert_eq!(hyphen, DistanceType::Hyphen);
+		assert!(hyphen.is_known());
+		let custom: LineWidthType = "glissando".parse().unwrap();
+		assert_eq!(custom, LineWidthType::Other("glissando".to_string()));
+		assert!(!custom.is_known());
+		assert_eq!(custom.to_string(), "glissando");
+		assert_eq!(LineWidthType::HeavyBarline.as_str(), "heavy barline");
+	}
+
+	#[test]
+	fn schema_defaults() {
+		assert_eq!(CancelLocation::default(), CancelLocation::Left);
+		assert_eq!(GroupSymbolValue::default(), GroupSymbolValue::None);
+		assert_eq!(ShowFrets::default(), ShowFrets::Numbers);
+		assert_eq!(TextDirection::default(), TextDirection::Ltr);
+		assert_eq!(TimeSeparator::default(), TimeSeparator::None);
+		assert_eq!(TimeSymbol::default(), TimeSymbol::Normal);
+		assert_eq!(UprightInverted::default(), UprightInverted::Upright);
+		assert_eq!(Winged::default(), Winged::None);
+		assert_eq!(XlinkActuate::default().as_str(), "onRequest");
+		assert_eq!(XlinkShow::default().as_str(), "replace");
+	}
+
+	#[test]
+	fn midi_values() {
+		assert_eq!("16".parse::<Midi16>().map(Midi16::get), Ok(16));
+		assert!("0".parse::<Midi16>().is_err());
+		assert!("17".parse::<Midi16>().is_err());
+		assert_eq!(" 128 ".parse::<Midi128>().map(Midi128::to_midi), Ok(127));
+		assert!("129".parse::<Midi128>().is_err());
+		assert_eq!(Midi16384::new(16384).map(|bank| bank.to_string()), Some("16384".to_string()));
+		assert_eq!(Midi16384::new(16385), None);
+	}
+}
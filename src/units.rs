@@ -0,0 +1,92 @@
+use crate::types::*;
+
+/// The number of millimeters in an inch, used to convert to and from pixels at a given resolution.
+pub const MILLIMETERS_PER_INCH: f64 = 25.4;
+
+/// Converts a length in millimeters to pixels at the given resolution in dots per inch.
+pub fn mm_to_pixels(millimeters: Millimeters, dpi: f64) -> f64 {
+	millimeters / MILLIMETERS_PER_INCH * dpi
+}
+
+/// Converts a length in pixels at the given resolution in dots per inch to millimeters.
+pub fn pixels_to_mm(pixels: f64, dpi: f64) -> Millimeters {
+	pixels / dpi * MILLIMETERS_PER_INCH
+}
+
+impl Scaling {
+	/// Converts a distance in global tenths to millimeters.
+	pub fn tenths_to_mm(&self, tenths: Tenths) -> Millimeters {
+		tenths * self.millimeters / self.tenths
+	}
+
+	/// Converts a distance in millimeters to global tenths.
+	pub fn mm_to_tenths(&self, millimeters: Millimeters) -> Tenths {
+		millimeters * self.tenths / self.millimeters
+	}
+
+	/// Converts a distance in global tenths to pixels at the given resolution in dots per inch.
+	pub fn tenths_to_pixels(&self, tenths: Tenths, dpi: f64) -> f64 {
+		mm_to_pixels(self.tenths_to_mm(tenths), dpi)
+	}
+
+	/// Converts a distance in pixels at the given resolution in dots per inch to global tenths.
+	pub fn pixels_to_tenths(&self, pixels: f64, dpi: f64) -> Tenths {
+		self.mm_to_tenths(pixels_to_mm(pixels, dpi))
+	}
+
+	/// Returns the height of a staff space, the distance between two adjacent staff lines, in millimeters.
+	pub fn staff_space(&self) -> Millimeters {
+		self.tenths_to_mm(10.0)
+	}
+
+	/// Returns the height of a staff space in millimeters on a staff with the given staff-size.
+	pub fn staff_space_for(&self, staff_size: Option<&StaffSize>) -> Millimeters {
+		self.staff_space() * staff_size.map_or(1.0, StaffSize::staff_factor)
+	}
+
+	/// Converts a distance in local tenths of a staff with the given staff-size, where ten tenths are one staff space of that staff, to millimeters.
+	pub fn staff_tenths_to_mm(&self, tenths: Tenths, staff_size: Option<&StaffSize>) -> Millimeters {
+		self.tenths_to_mm(tenths) * staff_size.map_or(1.0, StaffSize::staff_factor)
+	}
+}
+
+impl StaffSize {
+	/// Returns the factor by which the staff space of this staff is scaled relative to the default scaling.
+	pub fn staff_factor(&self) -> f64 {
+		self.value / 100.0
+	}
+
+	/// Returns the factor by which the notation on this staff is scaled. Without a scaling attribute the notation is scaled along with the staff space.
+	pub fn notation_factor(&self) -> f64 {
+		self.scaling.unwrap_or(self.value) / 100.0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn conversions() {
+		let scaling = Scaling { millimeters: 7.0, tenths: 40.0 };
+		assert_eq!(scaling.tenths_to_mm(40.0), 7.0);
+		assert_eq!(scaling.mm_to_tenths(1.75), 10.0);
+		assert_eq!(mm_to_pixels(25.4, 96.0), 96.0);
+		assert_eq!(pixels_to_mm(48.0, 96.0), 12.7);
+		assert_eq!(scaling.pixels_to_tenths(scaling.tenths_to_pixels(20.0, 300.0), 300.0), 20.0);
+	}
+
+	#[test]
+	fn staff_size() {
+		let scaling = Scaling { millimeters: 7.0, tenths: 40.0 };
+		let staff_size = StaffSize { value: 50.0, scaling: None };
+		assert_eq!(scaling.staff_space(), 1.75);
+		assert_eq!(scaling.staff_space_for(None), 1.75);
+		assert_eq!(scaling.staff_space_for(Some(&staff_size)), 0.875);
+		assert_eq!(scaling.staff_tenths_to_mm(40.0, Some(&staff_size)), 3.5);
+		assert_eq!(staff_size.notation_factor(), 0.5);
+		let staff_size = StaffSize { value: 150.0, scaling: Some(100.0) };
+		assert_eq!(staff_size.staff_factor(), 1.5);
+		assert_eq!(staff_size.notation_factor(), 1.0);
+	}
+}